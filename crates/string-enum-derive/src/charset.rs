@@ -0,0 +1,107 @@
+//! Restrictions on the characters allowed in variant names.
+
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::{self};
+
+use self::Charset::*;
+
+/// A named set of characters that every variant name must be drawn from.
+#[derive(Copy, Clone, PartialEq)]
+pub enum Charset {
+    /// Any ASCII character.
+    Ascii,
+    /// Printable ASCII, excluding space and control characters.
+    AsciiGraphic,
+    /// The RFC 3986 unreserved characters: `A-Z a-z 0-9 - . _ ~`.
+    UrlSafe,
+    /// An ASCII identifier: a letter or `_`, followed by letters, digits or
+    /// `_`.
+    Identifier,
+}
+
+static CHARSETS: &[(&str, Charset)] = &[
+    ("ascii", Ascii),
+    ("ascii_graphic", AsciiGraphic),
+    ("url_safe", UrlSafe),
+    ("identifier", Identifier),
+];
+
+impl Charset {
+    pub fn from_str(charset_str: &str) -> Result<Self, ParseError> {
+        for (name, charset) in CHARSETS {
+            if charset_str == *name {
+                return Ok(*charset);
+            }
+        }
+        Err(ParseError {
+            unknown: charset_str,
+        })
+    }
+
+    pub fn name(self) -> &'static str {
+        for (name, charset) in CHARSETS {
+            if self == *charset {
+                return name;
+            }
+        }
+        unreachable!()
+    }
+
+    /// Checks `name` against the charset, describing the first problem found.
+    pub fn check(self, name: &str) -> Result<(), String> {
+        let allowed = |i: usize, ch: char| match self {
+            Ascii => ch.is_ascii(),
+            AsciiGraphic => ch.is_ascii_graphic(),
+            UrlSafe => ch.is_ascii_alphanumeric() || matches!(ch, '-' | '.' | '_' | '~'),
+            Identifier if i == 0 => ch.is_ascii_alphabetic() || ch == '_',
+            Identifier => ch.is_ascii_alphanumeric() || ch == '_',
+        };
+
+        if name.is_empty() && self == Identifier {
+            return Err("an identifier cannot be empty".to_owned());
+        }
+
+        match name.char_indices().find(|&(i, ch)| !allowed(i, ch)) {
+            Some((_, ch)) => Err(format!("{ch:?} is not allowed")),
+            None => Ok(()),
+        }
+    }
+}
+
+pub struct ParseError<'a> {
+    unknown: &'a str,
+}
+
+impl<'a> Display for ParseError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("unknown charset `charset = ")?;
+        Debug::fmt(self.unknown, f)?;
+        f.write_str("`, expected one of ")?;
+        for (i, (name, _charset)) in CHARSETS.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            Debug::fmt(name, f)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn check_charsets() {
+    for &(name, ascii, graphic, url_safe, identifier) in &[
+        ("snake_case", true, true, true, true),
+        ("kebab-case", true, true, true, false),
+        ("foo bar", true, false, false, false),
+        ("a/b", true, true, false, false),
+        ("2fa", true, true, true, false),
+        ("", true, true, true, false),
+        ("café", false, false, false, false),
+    ] {
+        assert_eq!(Ascii.check(name).is_ok(), ascii, "{name:?}");
+        assert_eq!(AsciiGraphic.check(name).is_ok(), graphic, "{name:?}");
+        assert_eq!(UrlSafe.check(name).is_ok(), url_safe, "{name:?}");
+        assert_eq!(Identifier.check(name).is_ok(), identifier, "{name:?}");
+    }
+}
@@ -0,0 +1,122 @@
+//! Enums with data-carrying variants, which get an inherent `as_str` and
+//! `Display`, and `FromStr` with `#[str(default_fields)]`, but not
+//! `StringEnum`. With `#[str(kind = ...)]`, they get a companion unit enum
+//! instead.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse_quote;
+use syn::Fields;
+
+use crate::Enum;
+use crate::EnumVariant;
+
+impl Enum {
+    pub(crate) fn has_data_variants(&self) -> bool {
+        self.variants.iter().any(|v| !v.is_unit())
+    }
+
+    pub(crate) fn derive_data(&self) -> TokenStream {
+        let Enum {
+            vis,
+            ident,
+            generics,
+            options,
+            variants,
+            ..
+        } = self;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let remainder = self.match_remainder();
+        let as_str_arms = variants.iter().map(|v| {
+            let pattern = v.pattern();
+            let name = self.serialize_name(v);
+            quote!(#pattern => #name)
+        });
+
+        let impl_display = (!options.no_display).then(|| {
+            quote! {
+                impl #impl_generics ::core::fmt::Display for #ident #ty_generics #where_clause {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        ::core::fmt::Formatter::pad(f, self.as_str())
+                    }
+                }
+            }
+        });
+
+        let kind = options.kind.as_ref().map(|kind| self.derive_kind(kind));
+
+        let impl_from_str = (options.default_fields && !options.no_from_str).then(|| {
+            let arms = self.parsed_variants().flat_map(|v| {
+                let value = v.default_value();
+                self.accepted_names(v)
+                    .into_iter()
+                    .map(move |name| quote!(#name => ::core::result::Result::Ok(#value)))
+            });
+            let invalid_variant = self.invalid_variant_error(|v| self.deserialize_name(v));
+
+            // Every field of a parsed variant is defaulted, so its type must
+            // implement `Default`.
+            let mut generics = generics.clone();
+            let predicates = &mut generics.make_where_clause().predicates;
+            for field in self.parsed_variants().flat_map(|v| &v.fields) {
+                let ty = &field.ty;
+                predicates.push(parse_quote!(#ty: ::core::default::Default));
+            }
+
+            self.derive_from_str(&generics, quote! {
+                match s {
+                    #(#arms,)*
+                    _ => ::core::result::Result::Err(#invalid_variant),
+                }
+            })
+        });
+
+        quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Returns the name of the variant, ignoring its fields.
+                #vis fn as_str(&self) -> &'static str {
+                    match *self {
+                        #(#as_str_arms,)*
+                        #remainder
+                    }
+                }
+            }
+
+            #impl_display
+
+            #impl_from_str
+
+            #kind
+        }
+    }
+}
+
+impl EnumVariant {
+    /// A pattern matching the variant regardless of its fields.
+    pub(crate) fn pattern(&self) -> TokenStream {
+        let ident = &self.ident;
+        match self.fields {
+            Fields::Unit => quote!(Self::#ident),
+            Fields::Unnamed(_) => quote!(Self::#ident(..)),
+            Fields::Named(_) => quote!(Self::#ident { .. }),
+        }
+    }
+
+    /// The variant with every field set to `Default::default()`.
+    fn default_value(&self) -> TokenStream {
+        let ident = &self.ident;
+        let default = quote!(::core::default::Default::default());
+        match &self.fields {
+            Fields::Unit => quote!(Self::#ident),
+            Fields::Unnamed(fields) => {
+                let values = fields.unnamed.iter().map(|_| &default);
+                quote!(Self::#ident(#(#values),*))
+            }
+            Fields::Named(fields) => {
+                let names = fields.named.iter().map(|field| &field.ident);
+                quote!(Self::#ident { #(#names: #default),* })
+            }
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! The error type of the generated `FromStr` implementation.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Generics;
+use syn::Ident;
+
+use crate::Enum;
+use crate::EnumVariant;
+
+impl Enum {
+    /// Implements `FromStr` for the enum with `generics` given `body`,
+    /// statements evaluating to a `Result<Self, InvalidVariantError>`. The
+    /// error is converted if the enum has a custom `error` or `error_type`.
+    pub(crate) fn derive_from_str(&self, generics: &Generics, body: TokenStream) -> TokenStream {
+        let Enum { ident, options, .. } = self;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let (error, body) = self.convert_error(body);
+        let error_type = match (&options.error, &options.error_type) {
+            (None, Some(error_type)) => Some(self.derive_error_type(error_type)),
+            _ => None,
+        };
+
+        quote! {
+            impl #impl_generics ::core::str::FromStr for #ident #ty_generics #where_clause {
+                type Err = #error;
+
+                fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                    #body
+                }
+            }
+
+            #error_type
+        }
+    }
+
+    /// Returns the error type of `FromStr`, and `body`, statements evaluating
+    /// to a `Result<Self, InvalidVariantError>`, with the error converted to
+    /// it.
+    pub(crate) fn convert_error(&self, body: TokenStream) -> (TokenStream, TokenStream) {
+        let options = &self.options;
+        let krate = self.crate_path();
+
+        let (error, convert) = match (&options.error, &options.error_type) {
+            (Some(error), _) => match &options.error_with {
+                Some(error_with) => (quote!(#error), quote!(#error_with)),
+                None => (quote!(#error), quote!(::core::convert::From::from)),
+            },
+            (None, Some(error_type)) => (quote!(#error_type), quote!(::core::convert::From::from)),
+            (None, None) => return (quote!(#krate::InvalidVariantError), body),
+        };
+
+        let body = quote! {
+            let result: ::core::result::Result<Self, #krate::InvalidVariantError> = { #body };
+            ::core::result::Result::map_err(result, #convert)
+        };
+        (error, body)
+    }
+
+    /// Constructs the `InvalidVariantError` for unknown input, listing the
+    /// expected variants by `name` and applying `expecting` and `max_listed`.
+    pub(crate) fn invalid_variant_error(
+        &self,
+        name: impl Fn(&EnumVariant) -> String,
+    ) -> TokenStream {
+        let krate = self.crate_path();
+        let names = self.expected_variants().map(name);
+        let expecting = self
+            .options
+            .expecting
+            .as_ref()
+            .map(|expecting| quote!(.with_expecting(#expecting)));
+        let max_listed = self
+            .options
+            .max_listed
+            .map(|max_listed| quote!(.with_max_listed(#max_listed)));
+
+        quote! {{
+            const EXPECTED: &[&str] = &[#(#names,)*];
+            #krate::InvalidVariantError::new(EXPECTED)#expecting #max_listed
+        }}
+    }
+
+    /// Generates `error_type`, a wrapper around `InvalidVariantError` whose
+    /// message names the enum.
+    fn derive_error_type(&self, error_type: &Ident) -> TokenStream {
+        let Enum { vis, ident, .. } = self;
+        let krate = self.crate_path();
+        let type_name = ident.to_string();
+        let doc = format!("The error returned when parsing a [`{ident}`] fails.");
+
+        quote! {
+            #[doc = #doc]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            #vis struct #error_type(#krate::InvalidVariantError);
+
+            impl #error_type {
+                /// Returns the underlying error.
+                #vis const fn into_inner(self) -> #krate::InvalidVariantError {
+                    self.0
+                }
+            }
+
+            impl ::core::convert::From<#krate::InvalidVariantError> for #error_type {
+                fn from(error: #krate::InvalidVariantError) -> Self {
+                    Self(error.with_type_name(#type_name))
+                }
+            }
+
+            impl ::core::fmt::Display for #error_type {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::fmt::Display::fmt(&self.0, f)
+                }
+            }
+
+            impl ::core::error::Error for #error_type {}
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Companion unit enums of data-carrying enums, declared with
+//! `#[str(kind = Name)]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Fields;
+use syn::Generics;
+use syn::Ident;
+
+use crate::options::EnumOptions;
+use crate::Enum;
+use crate::EnumVariant;
+
+impl Enum {
+    /// Generates the unit enum `kind` with the variants of this enum, derives
+    /// `StringEnum` for it with the same names and options, and adds the
+    /// `kind()` method mapping to it.
+    pub(crate) fn derive_kind(&self, kind: &Ident) -> TokenStream {
+        let Enum {
+            vis,
+            ident,
+            generics,
+            non_exhaustive,
+            variants,
+            ..
+        } = self;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let kind_enum = Enum {
+            ident: kind.clone(),
+            generics: Generics::default(),
+            options: EnumOptions {
+                kind: None,
+                default_fields: false,
+                ..self.options.clone()
+            },
+            variants: variants
+                .iter()
+                .map(|v| EnumVariant {
+                    fields: Fields::Unit,
+                    ..v.clone()
+                })
+                .collect(),
+            ..self.clone()
+        };
+        let derived = kind_enum.derive();
+
+        let kind_variants = variants.iter().map(|v| &v.ident);
+        let non_exhaustive = non_exhaustive.then(|| quote!(#[non_exhaustive]));
+        let doc = format!("The kind of [`{ident}`], without its data.");
+        let method_doc = format!("Returns the [`{kind}`] of the variant.");
+
+        let remainder = self.match_remainder();
+        let arms = variants.iter().map(|v| {
+            let pattern = v.pattern();
+            let variant = &v.ident;
+            quote!(#pattern => #kind::#variant)
+        });
+
+        quote! {
+            #[doc = #doc]
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::hash::Hash,
+                ::core::cmp::PartialOrd,
+                ::core::cmp::Ord,
+            )]
+            #non_exhaustive
+            #vis enum #kind {
+                #(#kind_variants,)*
+            }
+
+            #derived
+
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #[doc = #method_doc]
+                #vis fn kind(&self) -> #kind {
+                    match *self {
+                        #(#arms,)*
+                        #remainder
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! Localized labels, declared with `#[str(label(...))]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::Enum;
+
+impl Enum {
+    /// Every locale that a variant has a label for, in order of first use.
+    pub(crate) fn locales(&self) -> Vec<String> {
+        let mut locales = Vec::new();
+        for variant in &self.variants {
+            for (locale, _) in &variant.options.labels {
+                let locale = locale.value();
+                if !locales.contains(&locale) {
+                    locales.push(locale);
+                }
+            }
+        }
+        locales
+    }
+
+    pub(crate) fn derive_labels(&self) -> TokenStream {
+        let Enum {
+            vis,
+            ident,
+            options,
+            variants,
+            ..
+        } = self;
+
+        let locales = self.locales();
+        if locales.is_empty() {
+            return TokenStream::new();
+        }
+
+        let remainder = self.match_remainder();
+
+        let arms = variants.iter().map(|v| {
+            let ident = &v.ident;
+            let labels = &v.options.labels;
+            let arms = labels
+                .iter()
+                .map(|(locale, label)| quote!(#locale => ::core::option::Option::Some(#label)));
+            let fallback = options.fallback_locale.as_ref().and_then(|fallback| {
+                labels
+                    .iter()
+                    .find(|(locale, _)| locale.value() == fallback.value())
+            });
+            let fallback = match fallback {
+                Some((_, label)) => quote!(::core::option::Option::Some(#label)),
+                None => quote!(::core::option::Option::None),
+            };
+
+            quote! {
+                Self::#ident => match locale {
+                    #(#arms,)*
+                    _ => #fallback,
+                }
+            }
+        });
+
+        quote! {
+            impl #ident {
+                /// Every locale with a label, in order of first use.
+                #vis const LOCALES: &'static [&'static str] = &[#(#locales,)*];
+
+                /// Returns the label of the variant in `locale`, falling back to
+                /// the fallback locale if one is configured.
+                #vis fn label(&self, locale: &str) -> ::core::option::Option<&'static str> {
+                    match *self {
+                        #(#arms,)*
+                        #remainder
+                    }
+                }
+            }
+        }
+    }
+}
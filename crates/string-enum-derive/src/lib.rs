@@ -1,26 +1,169 @@
 mod case;
+mod charset;
+mod data;
+mod error;
+mod kind;
+mod label;
+mod options;
+mod predicates;
+mod prefix;
+mod props;
+mod regex;
 mod rename;
+mod repr;
+mod stable_id;
+mod validate;
 
-use proc_macro2::Delimiter;
-use proc_macro2::Group;
 use proc_macro2::TokenStream;
 use quote::quote;
 use quote::ToTokens;
 use rename::ParseLitStr;
+use syn::meta::ParseNestedMeta;
+use syn::spanned::Spanned;
 use syn::Attribute;
 use syn::Data;
 use syn::DeriveInput;
 use syn::Error;
+use syn::Fields;
+use syn::Generics;
 use syn::Ident;
 use syn::LitStr;
-use syn::MacroDelimiter;
 use syn::Meta;
 use syn::MetaList;
 use syn::Variant;
+use syn::Visibility;
 
 use crate::case::RenameRule;
+use crate::options::EnumOptions;
+use crate::options::ParseOptions;
+use crate::options::VariantOptions;
 use crate::rename::RenameAttr;
-
+use crate::rename::RenameParts;
+
+/// Derives `StringEnum`, `FromStr` and `Display` for an enum of unit variants.
+///
+/// An enum with tuple or struct variants instead gets an inherent `as_str`,
+/// returning the name of the variant regardless of its fields, and `Display`.
+/// With `#[str(default_fields)]` it also implements `FromStr`, setting every
+/// field to `Default::default()`. Rename rules and the options concerning
+/// names and `FromStr` apply as usual; the others are errors.
+///
+/// Alternatively, `#[str(kind = MessageKind)]` on such an enum generates a
+/// companion enum `MessageKind` of unit variants with the same names, deriving
+/// `StringEnum`, `FromStr` and `Display` from every `str` and `serde` attribute
+/// given, along with a `kind()` method returning it. The data enum keeps its
+/// `as_str` and `Display`.
+///
+/// # Enum attributes
+///
+/// - `#[str = "rule"]` or `#[str(serialize = "rule", deserialize = "rule")]`:
+///   the rename rule applied to every variant, e.g. `"snake_case"`. Falls back
+///   to `#[serde(rename_all = ...)]`, separately for each half.
+/// - `#[str(charset = "...")]`: require every name to consist of `"ascii"`,
+///   `"ascii_graphic"`, `"url_safe"` or `"identifier"` characters.
+/// - `#[str(non_empty)]`: reject empty names.
+/// - `#[str(max_len = N)]`: reject names longer than `N` bytes.
+/// - `#[str(strict_roundtrip)]`: require the name returned by `as_str` to parse
+///   back into the same variant through `FromStr`.
+/// - `#[str(repr(short, label = "rule"))]`: declare additional named string
+///   representations, each with an optional rename rule. Every repr `name` gets
+///   an inherent `as_name` method and a `from_name_str` parser. Variants
+///   without a rule or explicit value use their serialize name. A repr cannot
+///   be named `str`.
+/// - `#[str(alternate = "label")]`: use the given repr for `{:#}` formatting.
+/// - `#[str(no_from_str)]`, `#[str(no_display)]`: do not implement `FromStr` or
+///   `Display`, e.g. to provide a custom implementation. `StringEnum` is always
+///   implemented.
+/// - `#[str(error = Type)]`: use `Type` as the error of `FromStr`, converted
+///   from `string_enum::InvalidVariantError` with `From`, or with the function
+///   given by `#[str(error_with = path)]`.
+/// - `#[str(error_type = Name)]`: generate an error type `Name` for `FromStr`
+///   that wraps `string_enum::InvalidVariantError` and names the enum in its
+///   message.
+/// - `#[str(expecting = "a log level")]`: describe the expected input in parse
+///   errors instead of listing the variants.
+/// - `#[str(max_listed = N)]`: list at most `N` variants in parse errors,
+///   summarizing the rest as "... and 3 more". `N` must be at least 1.
+/// - `#[str(fallback_locale = "en")]`: the locale whose label `label` returns
+///   for locales a variant has no label for. Every variant must have a label
+///   for it.
+/// - `#[str(stable_id)]`: generate inherent `const fn stable_id(self) -> u32`
+///   and `from_stable_id`. The id of a variant is the 32-bit FNV-1a hash of its
+///   serialize name, as computed by `string_enum::stable_id`, so it stays the
+///   same across releases as long as the name does. Colliding ids are compile
+///   errors.
+/// - `#[str(predicates)]`: generate an inherent `const fn is_variant(&self)`
+///   for every variant, named in snake_case, and one for every group, e.g.
+///   `is_terminal` for `#[str(group = "terminal")]`.
+/// - `#[str(crate = "path")]` or `#[str(crate = path)]`: the path to the
+///   `string_enum` crate in generated code, by default `::string_enum`.
+/// - `#[str(impl_default)]`: implement `Default` returning the fallback
+///   variant. Do not combine with `#[derive(Default)]`.
+///
+/// # Variant attributes
+///
+/// - `#[str = "name"]` or `#[str(serialize = "name", deserialize = "name")]`:
+///   the name of the variant, overriding the rename rule. Falls back to
+///   `#[serde(rename = ...)]`, separately for each half.
+/// - `#[str(alias = "name")]` or `#[serde(alias = "name")]`: an additional name
+///   to parse as this variant; may be repeated. Aliases are not part of
+///   `StringEnum::DESERIALIZE_NAMES` nor listed as expected in parse errors.
+/// - `#[str(repr(short = "us", label = "United States"))]`: the value of each
+///   declared repr for this variant.
+/// - `#[str(default)]`, `#[serde(other)]` or `#[default]`: parse unknown input
+///   as this variant instead of failing. An inherent `from_str_strict` still
+///   reports the error, as the `FromStr` error type, and so does
+///   `StringEnum::parse_any`, as an `InvalidVariantError`.
+/// - `#[str(skip_parse)]` or `#[serde(skip_deserializing)]`: never parse this
+///   variant, nor list it as expected in parse errors.
+/// - `#[str(skip_list)]` or `#[serde(skip_serializing)]`: leave this variant
+///   out of `StringEnum::VARIANTS` and `StringEnum::SERIALIZE_NAMES`.
+/// - `#[str(skip)]` or `#[serde(skip)]`: both of the above.
+/// - `#[str(hidden)]` or `#[doc(hidden)]`: parse this variant, but do not list
+///   it as expected in parse errors.
+/// - `#[str(prop(unit = "m", color = "red"))]`: arbitrary properties, returned
+///   by inherent `props` and `get_prop` methods.
+/// - `#[str(label(en = "Pending", de = "Ausstehend"))]`: user-facing labels per
+///   locale, returned by an inherent `label(locale)` method. Locales that are
+///   not identifiers are quoted, as in `label("pt-BR" = "Pendente")`, and
+///   `label` matches them exactly. The inherent `LOCALES` constant lists every
+///   locale used, in order of first use.
+/// - `#[str(code = 17)]`: the stable id of this variant, overriding the hash of
+///   its name. Requires `#[str(stable_id)]` on the enum.
+/// - `#[str(group = "terminal")]`: tag this variant with a group; may be
+///   repeated. Generates inherent `groups` and `in_group` methods, the latter
+///   iterating over the members of a group in `StringEnum::VARIANTS`.
+///
+/// As with serde, the `serialize` and `deserialize` halves may be given in
+/// separate attributes, but giving the same half twice is an error.
+///
+/// `as_str` and the repr accessors return a name for every variant, skipped
+/// or not.
+///
+/// `StringEnum::parse_prefix` and `StringEnum::find_iter` match the longest
+/// deserialize name at a position through byte tries, one exact and one ASCII
+/// case-insensitive, compiled into nested `match` expressions.
+///
+/// Parse errors are `string_enum::InvalidVariantError`, whose message can be
+/// localized with `string_enum::Wording`.
+///
+/// Duplicate names and names violating the constraints above are compile
+/// errors.
+///
+/// # Crate path
+///
+/// Generated code refers to `::string_enum`. If the crate is renamed in
+/// `Cargo.toml` or only reachable through a re-export, set the path with
+/// `#[str(crate = "...")]`. A facade crate can bake its own path in by
+/// wrapping the derive in a `macro_rules!` macro that passes
+/// `#[str(crate = $crate::string_enum)]`, where `string_enum` is the facade's
+/// re-export of this crate.
+///
+/// Variants disabled by `#[cfg(...)]` are removed by the compiler before the
+/// derive runs, so every generated table and `match` only covers the variants
+/// of the active configuration. Likewise, `#[cfg_attr(predicate, ...)]` is
+/// expanded beforehand, so `str` and `serde` attributes inside it apply exactly
+/// when the predicate holds.
 #[proc_macro_derive(StringEnum, attributes(str))]
 pub fn derive_string_enum(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = syn::parse_macro_input!(input as DeriveInput);
@@ -30,81 +173,199 @@ pub fn derive_string_enum(input: proc_macro::TokenStream) -> proc_macro::TokenSt
         .into()
 }
 
+#[derive(Clone)]
 struct Enum {
+    vis: Visibility,
     ident: Ident,
+    generics: Generics,
     non_exhaustive: bool,
     rename_all: Option<RenameAttr<RenameRule>>,
+    options: EnumOptions,
     variants: Vec<EnumVariant>,
 }
 
+#[derive(Clone)]
 struct EnumVariant {
     ident: Ident,
+    fields: Fields,
+    /// `#[default]`, which only makes the variant the fallback if every
+    /// variant is a unit variant, since it is also used with
+    /// `#[derive(Default)]` on data-carrying enums.
+    core_default: bool,
     rename: Option<RenameAttr<LitStr>>,
+    options: VariantOptions,
 }
 
-struct Attrs<T> {
+struct Attrs<T, O> {
     non_exhaustive: bool,
+    default: bool,
+    doc_hidden: bool,
     rename: Option<RenameAttr<T>>,
-}
-
-enum Source {
-    Str,
-    Serde,
+    options: O,
 }
 
 enum AttrTokens {
     Skip,
     NonExhaustive,
-    Str(Source, proc_macro2::Span, TokenStream),
+    Default,
+    DocHidden,
+    Str(proc_macro2::Span, TokenStream),
+    StrList(MetaList),
+    SerdeList(MetaList),
 }
 
 impl Enum {
     fn derive(&self) -> TokenStream {
+        if self.has_data_variants() {
+            return self.derive_data();
+        }
+
         let Enum {
-            ident,
-            non_exhaustive,
-            rename_all,
-            variants,
+            ident, variants, ..
         } = self;
+        let krate = self.crate_path();
 
-        let len_variants = variants.len();
-
-        let const_variants_elems = variants.iter().map(|v| {
+        let const_variants_elems = self.listed_variants().map(|v| {
             let ident = &v.ident;
             quote!(Self::#ident)
         });
 
         let as_str_arms = variants.iter().map(|v| {
             let ident = &v.ident;
-            let name = variant_name(ident, rename_all.serialize_ref(), v.serialize_ref());
+            let name = self.serialize_name(v);
             quote!(Self::#ident => #name)
         });
-        let as_str_remainder = if *non_exhaustive {
-            quote!(_ => {
-                #[cold]
-                fn non_exhaustive_unreachable() -> ! {
-                    ::core::unreachable!("non-exhaustive enum")
-                }
-                non_exhaustive_unreachable()
-            })
-        } else {
-            TokenStream::new()
-        };
+        let as_str_remainder = self.match_remainder();
 
-        let from_str_variants = variants.iter().map(|v| {
+        let mut listed = 0usize..;
+        let position_arms = variants.iter().map(|v| {
             let ident = &v.ident;
-            variant_name(ident, rename_all.deserialize_ref(), v.deserialize_ref())
+            match v.listed().then(|| listed.next()).flatten() {
+                Some(position) => quote!(Self::#ident => ::core::option::Option::Some(#position)),
+                None => quote!(Self::#ident => ::core::option::Option::None),
+            }
         });
 
-        let from_str_arms = variants.iter().map(|v| {
+        let serialize_names = self.listed_variants().map(|v| self.serialize_name(v));
+
+        // Every name `as_str` can return, including those of unlisted variants.
+        let lens = variants.iter().map(|v| self.serialize_name(v).len());
+        let max_len = lens.clone().max().unwrap_or(0);
+        let min_len = lens.min().unwrap_or(0);
+        let deserialize_names: Vec<_> = self
+            .parsed_variants()
+            .map(|v| self.deserialize_name(v))
+            .collect();
+        let accepted_names: Vec<_> = self
+            .parsed_variants()
+            .flat_map(|v| self.accepted_names(v))
+            .collect();
+
+        let from_str_arms: Vec<_> = self
+            .parsed_variants()
+            .flat_map(|v| {
+                let ident = &v.ident;
+                self.accepted_names(v)
+                    .into_iter()
+                    .map(move |name| quote!(#name => ::core::result::Result::Ok(Self::#ident)))
+            })
+            .collect();
+
+        // Deserialize names and aliases take precedence; serialize names that
+        // are also accepted by `FromStr` would be unreachable.
+        let parse_any_arms = self.parsed_variants().filter_map(|v| {
             let ident = &v.ident;
-            let name = variant_name(ident, rename_all.deserialize_ref(), v.deserialize_ref());
-            quote!(#name => ::core::result::Result::Ok(Self::#ident))
+            let name = self.serialize_name(v);
+            if accepted_names.contains(&name) {
+                None
+            } else {
+                Some(quote!(#name => ::core::result::Result::Ok(Self::#ident)))
+            }
         });
 
+        let display_alternate = self.options.alternate.as_ref().map(|repr| {
+            let as_repr = repr::as_repr(&Ident::new(&repr.value(), repr.span()));
+            quote! {
+                if ::core::fmt::Formatter::alternate(f) {
+                    return ::core::fmt::Formatter::pad(f, self.#as_repr());
+                }
+            }
+        });
+        let impl_display = (!self.options.no_display).then(|| {
+            quote! {
+                impl ::core::fmt::Display for #ident {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        #display_alternate
+                        ::core::fmt::Formatter::pad(f, #krate::StringEnum::as_str(self))
+                    }
+                }
+            }
+        });
+
+        let invalid_variant = self.invalid_variant_error(|v| self.deserialize_name(v));
+
+        let from_str_strict = quote! {
+            match s {
+                #(#from_str_arms,)*
+                _ => ::core::result::Result::Err(#invalid_variant),
+            }
+        };
+
+        let (from_str, fallback) = match self.fallback() {
+            Some(EnumVariant {
+                ident: fallback, ..
+            }) => {
+                let vis = &self.vis;
+                let impl_default = self.options.impl_default.then(|| {
+                    quote! {
+                        impl ::core::default::Default for #ident {
+                            fn default() -> Self {
+                                Self::#fallback
+                            }
+                        }
+                    }
+                });
+                let from_str = quote! {
+                    ::core::result::Result::Ok(Self::from_str_strict(s).unwrap_or(Self::#fallback))
+                };
+                let (error, from_str_strict) = self.convert_error(from_str_strict);
+                let fallback = quote! {
+                    impl #ident {
+                        /// Parses a deserialize name like `FromStr`, but reports unknown input
+                        /// as an error instead of returning the fallback variant.
+                        #vis fn from_str_strict(s: &str) -> ::core::result::Result<Self, #error> {
+                            #from_str_strict
+                        }
+                    }
+
+                    #impl_default
+                };
+                (from_str, fallback)
+            }
+            None => (from_str_strict, TokenStream::new()),
+        };
+
+        let impl_from_str =
+            (!self.options.no_from_str).then(|| self.derive_from_str(&self.generics, from_str));
+
+        let prefix_matches = self.derive_prefix_matches();
+        let (regex, regex_anchored) = self.regex();
+
+        let reprs = self.derive_reprs();
+        let props = self.derive_props();
+        let labels = self.derive_labels();
+        let stable_ids = self.derive_stable_ids();
+        let predicates = self.derive_predicates();
+
         quote! {
-            impl string_enum::StringEnum for #ident {
+            impl #krate::StringEnum for #ident {
                 const VARIANTS: &'static [Self] = &[#(#const_variants_elems,)*];
+                const SERIALIZE_NAMES: &'static [&'static str] = &[#(#serialize_names,)*];
+                const DESERIALIZE_NAMES: &'static [&'static str] = &[#(#deserialize_names,)*];
+                const MAX_LEN: usize = #max_len;
+                const MIN_LEN: usize = #min_len;
+                const REGEX: &'static str = #regex;
+                const REGEX_ANCHORED: &'static str = #regex_anchored;
 
                 fn as_str(&self) -> &'static str {
                     match *self {
@@ -112,28 +373,125 @@ impl Enum {
                         #as_str_remainder
                     }
                 }
-            }
-
-            impl ::core::str::FromStr for #ident {
-                type Err = string_enum::InvalidVariantError;
-
-                fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
-                    const FROM_STR_VARIANTS: [&str; #len_variants] = [#(#from_str_variants,)*];
 
+                fn parse_any(s: &str) -> ::core::result::Result<Self, #krate::InvalidVariantError> {
                     match s {
                         #(#from_str_arms,)*
-                        _ => ::core::result::Result::Err(string_enum::InvalidVariantError::new(&FROM_STR_VARIANTS)),
+                        #(#parse_any_arms,)*
+                        _ => ::core::result::Result::Err(#invalid_variant),
                     }
                 }
-            }
 
-            impl ::core::fmt::Display for #ident {
-                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                    ::core::fmt::Formatter::pad(f, string_enum::StringEnum::as_str(self))
+                #prefix_matches
+
+                fn position(&self) -> ::core::option::Option<usize> {
+                    match *self {
+                        #(#position_arms,)*
+                        #as_str_remainder
+                    }
                 }
             }
+
+            #impl_from_str
+
+            #fallback
+
+            #impl_display
+
+            #reprs
+
+            #props
+
+            #labels
+
+            #stable_ids
+
+            #predicates
+        }
+    }
+
+    /// The path to the `string_enum` crate in generated code.
+    fn crate_path(&self) -> syn::Path {
+        match &self.options.krate {
+            Some(path) => path.clone(),
+            None => syn::parse_quote!(::string_enum),
         }
     }
+
+    /// The variants that `FromStr` accepts.
+    fn parsed_variants(&self) -> impl Iterator<Item = &EnumVariant> {
+        self.variants.iter().filter(|v| v.parsed())
+    }
+
+    /// The variants that appear in `StringEnum::VARIANTS`.
+    fn listed_variants(&self) -> impl Iterator<Item = &EnumVariant> {
+        self.variants.iter().filter(|v| v.listed())
+    }
+
+    /// The variants that parse errors list as expected.
+    fn expected_variants(&self) -> impl Iterator<Item = &EnumVariant> {
+        self.parsed_variants().filter(|v| !v.options.hidden)
+    }
+
+    /// The variant that `FromStr` returns for unknown input, if any.
+    fn fallback(&self) -> Option<&EnumVariant> {
+        self.variants.iter().find(|v| v.options.default)
+    }
+
+    /// The trailing arm of a `match *self`, needed when the enum is
+    /// `#[non_exhaustive]`.
+    fn match_remainder(&self) -> TokenStream {
+        if self.non_exhaustive {
+            quote!(_ => {
+                #[cold]
+                fn non_exhaustive_unreachable() -> ! {
+                    ::core::unreachable!("non-exhaustive enum")
+                }
+                non_exhaustive_unreachable()
+            })
+        } else {
+            TokenStream::new()
+        }
+    }
+
+    fn serialize_name(&self, variant: &EnumVariant) -> String {
+        variant_name(
+            &variant.ident,
+            self.rename_all.serialize_ref(),
+            variant.serialize_ref(),
+        )
+    }
+
+    fn deserialize_name(&self, variant: &EnumVariant) -> String {
+        variant_name(
+            &variant.ident,
+            self.rename_all.deserialize_ref(),
+            variant.deserialize_ref(),
+        )
+    }
+
+    /// The deserialize name followed by the aliases of the variant, every
+    /// name that `FromStr` accepts for it.
+    fn accepted_names(&self, variant: &EnumVariant) -> Vec<String> {
+        let aliases = variant.options.aliases.iter().map(LitStr::value);
+        std::iter::once(self.deserialize_name(variant))
+            .chain(aliases)
+            .collect()
+    }
+}
+
+impl EnumVariant {
+    fn is_unit(&self) -> bool {
+        matches!(self.fields, Fields::Unit)
+    }
+
+    fn parsed(&self) -> bool {
+        !self.options.skip_parse
+    }
+
+    fn listed(&self) -> bool {
+        !self.options.skip_list
+    }
 }
 
 impl TryFrom<DeriveInput> for Enum {
@@ -141,26 +499,43 @@ impl TryFrom<DeriveInput> for Enum {
 
     fn try_from(value: DeriveInput) -> syn::Result<Self> {
         let DeriveInput {
-            attrs, ident, data, ..
+            attrs,
+            vis,
+            ident,
+            generics,
+            data,
         } = value;
 
         let Attrs {
             rename: rename_all,
             non_exhaustive,
+            options,
+            ..
         } = Attrs::parse_attrs(attrs, "rename_all")?;
 
-        let variants = match data {
+        let mut variants: Vec<EnumVariant> = match data {
             Data::Enum(data) => data.variants.into_iter().map(TryFrom::try_from).collect(),
             Data::Struct(ref data) => Err(Error::new(data.struct_token.span, "expected enum")),
             Data::Union(ref data) => Err(Error::new(data.union_token.span, "expected enum")),
         }?;
 
-        Ok(Self {
+        if variants.iter().all(EnumVariant::is_unit) {
+            for variant in &mut variants {
+                variant.options.default |= variant.core_default;
+            }
+        }
+
+        let this = Self {
+            vis,
             ident,
+            generics,
             non_exhaustive,
             rename_all,
+            options,
             variants,
-        })
+        };
+        this.validate()?;
+        Ok(this)
     }
 }
 
@@ -175,55 +550,86 @@ impl TryFrom<Variant> for EnumVariant {
             ..
         } = value;
 
-        let Attrs { rename, .. } = Attrs::parse_attrs(attrs, "rename")?;
+        let Attrs {
+            rename,
+            default,
+            doc_hidden,
+            mut options,
+            ..
+        } = Attrs::<_, VariantOptions>::parse_attrs(attrs, "rename")?;
+        options.hidden |= doc_hidden;
 
-        if matches!(fields, syn::Fields::Unit) {
-            Ok(Self { ident, rename })
-        } else {
-            Err(Error::new(ident.span(), "expected unit variant"))
-        }
+        Ok(Self {
+            ident,
+            fields,
+            core_default: default,
+            rename,
+            options,
+        })
     }
 }
 
-impl<T: ParseLitStr> Attrs<T> {
+impl<T: ParseLitStr + Clone, O: ParseOptions> Attrs<T, O> {
     fn parse_attrs(attrs: Vec<Attribute>, serde_attr: &str) -> syn::Result<Self> {
-        let mut rename = None;
+        let mut str_rename = RenameParts::new();
+        let mut serde_rename = RenameParts::new();
         let mut non_exhaustive = false;
+        let mut default = false;
+        let mut doc_hidden = false;
+        let mut options = O::default();
 
         for attr in attrs {
-            let (source, span, tokens) = match get_attr_tokens(serde_attr, attr.meta)? {
-                AttrTokens::Skip => {
-                    continue;
-                }
+            match get_attr_tokens(attr.meta)? {
+                AttrTokens::Skip => {}
                 AttrTokens::NonExhaustive => {
                     non_exhaustive = true;
-                    continue;
                 }
-                AttrTokens::Str(source, span, tokens) => (source, span, tokens),
-            };
-
-            if matches!(&rename, Some((Source::Str, _))) {
-                match source {
-                    Source::Str => {
-                        return Err(Error::new(span, "duplicate #[str = \"...\"] attribute"));
-                    }
-                    Source::Serde => {
-                        continue;
-                    }
+                AttrTokens::Default => {
+                    default = true;
+                }
+                AttrTokens::DocHidden => {
+                    doc_hidden = true;
+                }
+                AttrTokens::Str(span, tokens) => {
+                    str_rename.merge(span, syn::parse2(tokens)?)?;
+                }
+                AttrTokens::StrList(meta) => {
+                    meta.parse_nested_meta(|meta| {
+                        if str_rename.parse_nested(&meta)? {
+                            Ok(())
+                        } else {
+                            options.parse_str(&meta)
+                        }
+                    })?;
+                }
+                AttrTokens::SerdeList(meta) => {
+                    meta.parse_nested_meta(|meta| {
+                        if meta.path.is_ident(serde_attr) {
+                            serde_rename.merge(meta.path.span(), meta.input.parse()?)?;
+                        } else {
+                            options.parse_serde(&meta)?;
+                            skip_meta_value(&meta)?;
+                        }
+                        Ok(())
+                    })?;
                 }
             }
-
-            rename = Some((source, syn::parse2(tokens)?));
         }
 
         Ok(Self {
             non_exhaustive,
-            rename: rename.map(|(_, v)| v),
+            default,
+            doc_hidden,
+            // Like serde, halves given in separate attributes are merged. Each
+            // half given with `str` takes precedence over the same half from
+            // `serde`.
+            rename: str_rename.or(serde_rename),
+            options,
         })
     }
 }
 
-fn get_attr_tokens(serde_attr: &str, meta: Meta) -> syn::Result<AttrTokens> {
+fn get_attr_tokens(meta: Meta) -> syn::Result<AttrTokens> {
     macro_rules! some {
         ($expr:expr) => {
             match $expr {
@@ -239,7 +645,11 @@ fn get_attr_tokens(serde_attr: &str, meta: Meta) -> syn::Result<AttrTokens> {
             let ident_str = ident.to_string();
             match ident_str.as_str() {
                 "non_exhaustive" => Ok(AttrTokens::NonExhaustive),
-                "str" => Err(Error::new(ident.span(), "expected #[str = \"...\"]")),
+                "default" => Ok(AttrTokens::Default),
+                "str" => Err(Error::new(
+                    ident.span(),
+                    "expected #[str = \"...\"] or #[str(...)]",
+                )),
                 _ => Ok(AttrTokens::Skip),
             }
         }
@@ -247,16 +657,9 @@ fn get_attr_tokens(serde_attr: &str, meta: Meta) -> syn::Result<AttrTokens> {
             let ident = some!(meta.path.get_ident());
             let ident_str = ident.to_string();
             match ident_str.as_str() {
-                "str" => Ok(AttrTokens::Str(
-                    Source::Str,
-                    ident.span(),
-                    surround(meta.delimiter, meta.tokens),
-                )),
-                "serde" => Ok(AttrTokens::Str(
-                    Source::Serde,
-                    ident.span(),
-                    some!(serde_rename(serde_attr, meta)?),
-                )),
+                "str" => Ok(AttrTokens::StrList(meta)),
+                "serde" => Ok(AttrTokens::SerdeList(meta)),
+                "doc" if meta.tokens.to_string() == "hidden" => Ok(AttrTokens::DocHidden),
                 _ => Ok(AttrTokens::Skip),
             }
         }
@@ -268,31 +671,20 @@ fn get_attr_tokens(serde_attr: &str, meta: Meta) -> syn::Result<AttrTokens> {
             let mut tokens = TokenStream::new();
             meta.eq_token.to_tokens(&mut tokens);
             meta.value.to_tokens(&mut tokens);
-            Ok(AttrTokens::Str(Source::Str, ident.span(), tokens))
+            Ok(AttrTokens::Str(ident.span(), tokens))
         }
     }
 }
 
-fn serde_rename(name: &str, meta: MetaList) -> syn::Result<Option<TokenStream>> {
-    let mut res = None;
-    meta.parse_nested_meta(|meta| {
-        if meta.path.is_ident(name) {
-            res = Some(meta.input.parse()?);
-        }
-        Ok(())
-    })?;
-    Ok(res)
-}
-
-fn surround(delimiter: MacroDelimiter, tokens: TokenStream) -> TokenStream {
-    let (delim, span) = match delimiter {
-        MacroDelimiter::Paren(paren) => (Delimiter::Parenthesis, paren.span),
-        MacroDelimiter::Brace(brace) => (Delimiter::Brace, brace.span),
-        MacroDelimiter::Bracket(bracket) => (Delimiter::Bracket, bracket.span),
-    };
-    let mut g = Group::new(delim, tokens);
-    g.set_span(span.join());
-    g.into_token_stream()
+/// Consumes the value of a nested meta item that was not otherwise parsed, so
+/// that unrelated `serde` options do not trip up the parser.
+fn skip_meta_value(meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.input.parse::<proc_macro2::TokenTree>()?;
+    }
+    Ok(())
 }
 
 fn variant_name(ident: &Ident, rename_all: Option<&RenameRule>, rename: Option<&LitStr>) -> String {
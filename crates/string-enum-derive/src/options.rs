@@ -0,0 +1,323 @@
+//! Options given in `#[str(...)]` alongside `serialize` and `deserialize`.
+
+use quote::ToTokens;
+use syn::meta::ParseNestedMeta;
+use syn::Ident;
+use syn::LitInt;
+use syn::LitStr;
+
+use crate::case::RenameRule;
+use crate::charset::Charset;
+use crate::rename::ParseLitStr;
+
+pub trait ParseOptions: Default {
+    /// Parses a single `#[str(...)]` entry that is not a rename.
+    fn parse_str(&mut self, meta: &ParseNestedMeta) -> syn::Result<()>;
+
+    /// Inspects a single `#[serde(...)]` entry that is not a rename. Any value
+    /// left unparsed is skipped.
+    fn parse_serde(&mut self, _meta: &ParseNestedMeta) -> syn::Result<()> {
+        Ok(())
+    }
+}
+
+/// Options on the enum itself.
+#[derive(Default, Clone)]
+pub struct EnumOptions {
+    /// `charset = "..."`
+    pub charset: Option<Charset>,
+    /// `non_empty`
+    pub non_empty: bool,
+    /// `max_len = N`
+    pub max_len: Option<usize>,
+    /// `strict_roundtrip`
+    pub strict_roundtrip: bool,
+    /// `repr(name, name = "rule", ...)`
+    pub reprs: Vec<Repr>,
+    /// `alternate = "name"`
+    pub alternate: Option<LitStr>,
+    /// `impl_default`
+    pub impl_default: bool,
+    /// `crate = "path"` or `crate = path`
+    pub krate: Option<syn::Path>,
+    /// `no_from_str`
+    pub no_from_str: bool,
+    /// `no_display`
+    pub no_display: bool,
+    /// `error = Type`
+    pub error: Option<syn::Type>,
+    /// `error_with = path`
+    pub error_with: Option<syn::Path>,
+    /// `error_type = Ident`
+    pub error_type: Option<Ident>,
+    /// `expecting = "..."`
+    pub expecting: Option<LitStr>,
+    /// `max_listed = N`
+    pub max_listed: Option<usize>,
+    /// `fallback_locale = "en"`
+    pub fallback_locale: Option<LitStr>,
+    /// `stable_id`
+    pub stable_id: bool,
+    /// `predicates`
+    pub predicates: bool,
+    /// `default_fields`
+    pub default_fields: bool,
+    /// `kind = Ident`
+    pub kind: Option<Ident>,
+}
+
+/// A named string representation declared with `#[str(repr(...))]` on the
+/// enum.
+#[derive(Clone)]
+pub struct Repr {
+    pub ident: Ident,
+    pub rule: Option<RenameRule>,
+}
+
+impl ParseOptions for EnumOptions {
+    fn parse_str(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("charset") {
+            let value = Charset::parse_lit_str(meta.value()?.parse()?)?;
+            set_once(meta, &mut self.charset, value)
+        } else if meta.path.is_ident("non_empty") {
+            set_flag(meta, &mut self.non_empty)
+        } else if meta.path.is_ident("max_len") {
+            let value = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+            set_once(meta, &mut self.max_len, value)
+        } else if meta.path.is_ident("strict_roundtrip") {
+            set_flag(meta, &mut self.strict_roundtrip)
+        } else if meta.path.is_ident("repr") {
+            meta.parse_nested_meta(|meta| {
+                let (ident, rule) = parse_repr_entry(&meta, self.reprs.iter().map(|r| &r.ident))?;
+                let rule = rule.map(RenameRule::parse_lit_str).transpose()?;
+                self.reprs.push(Repr { ident, rule });
+                Ok(())
+            })
+        } else if meta.path.is_ident("alternate") {
+            let value = meta.value()?.parse()?;
+            set_once(meta, &mut self.alternate, value)
+        } else if meta.path.is_ident("impl_default") {
+            set_flag(meta, &mut self.impl_default)
+        } else if meta.path.is_ident("no_from_str") {
+            set_flag(meta, &mut self.no_from_str)
+        } else if meta.path.is_ident("no_display") {
+            set_flag(meta, &mut self.no_display)
+        } else if meta.path.is_ident("crate") {
+            // A bare path allows `macro_rules!` wrappers to pass `$crate`.
+            let value = parse_maybe_quoted(meta)?;
+            set_once(meta, &mut self.krate, value)
+        } else if meta.path.is_ident("error") {
+            let value = parse_maybe_quoted(meta)?;
+            set_once(meta, &mut self.error, value)
+        } else if meta.path.is_ident("error_with") {
+            let value = parse_maybe_quoted(meta)?;
+            set_once(meta, &mut self.error_with, value)
+        } else if meta.path.is_ident("error_type") {
+            let value = parse_maybe_quoted(meta)?;
+            set_once(meta, &mut self.error_type, value)
+        } else if meta.path.is_ident("expecting") {
+            let value = meta.value()?.parse()?;
+            set_once(meta, &mut self.expecting, value)
+        } else if meta.path.is_ident("max_listed") {
+            let lit = meta.value()?.parse::<LitInt>()?;
+            let value = lit.base10_parse()?;
+            if value == 0 {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "`max_listed` must be at least 1",
+                ));
+            }
+            set_once(meta, &mut self.max_listed, value)
+        } else if meta.path.is_ident("fallback_locale") {
+            let value = meta.value()?.parse()?;
+            set_once(meta, &mut self.fallback_locale, value)
+        } else if meta.path.is_ident("stable_id") {
+            set_flag(meta, &mut self.stable_id)
+        } else if meta.path.is_ident("predicates") {
+            set_flag(meta, &mut self.predicates)
+        } else if meta.path.is_ident("default_fields") {
+            set_flag(meta, &mut self.default_fields)
+        } else if meta.path.is_ident("kind") {
+            let value = parse_maybe_quoted(meta)?;
+            set_once(meta, &mut self.kind, value)
+        } else {
+            Err(unknown(meta))
+        }
+    }
+}
+
+/// Options on a single variant.
+#[derive(Default, Clone)]
+pub struct VariantOptions {
+    /// `repr(name = "value", ...)`
+    pub reprs: Vec<(Ident, LitStr)>,
+    /// `default`, `#[serde(other)]` or, on enums of unit variants only,
+    /// `#[default]`
+    pub default: bool,
+    /// `skip_parse`, `skip`, `#[serde(skip_deserializing)]` or
+    /// `#[serde(skip)]`
+    pub skip_parse: bool,
+    /// `skip_list`, `skip`, `#[serde(skip_serializing)]` or `#[serde(skip)]`
+    pub skip_list: bool,
+    /// `hidden` or `#[doc(hidden)]`
+    pub hidden: bool,
+    /// `prop(key = "value", ...)`
+    pub props: Vec<(Ident, LitStr)>,
+    /// `group = "name"`, possibly repeated
+    pub groups: Vec<LitStr>,
+    /// `alias = "name"` or `#[serde(alias = "name")]`, possibly repeated
+    pub aliases: Vec<LitStr>,
+    /// `label(locale = "...", "pt-BR" = "...", ...)`
+    pub labels: Vec<(LitStr, LitStr)>,
+    /// `code = N`
+    pub code: Option<u32>,
+}
+
+impl ParseOptions for VariantOptions {
+    fn parse_str(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("repr") {
+            meta.parse_nested_meta(|meta| {
+                let (ident, value) = parse_repr_entry(&meta, self.reprs.iter().map(|(i, _)| i))?;
+                let value = value.ok_or_else(|| meta.error("expected `= \"...\"`"))?;
+                self.reprs.push((ident, value));
+                Ok(())
+            })
+        } else if meta.path.is_ident("default") {
+            set_flag(meta, &mut self.default)
+        } else if meta.path.is_ident("skip") {
+            set_flag(meta, &mut self.skip_parse)?;
+            set_flag(meta, &mut self.skip_list)
+        } else if meta.path.is_ident("skip_parse") {
+            set_flag(meta, &mut self.skip_parse)
+        } else if meta.path.is_ident("skip_list") {
+            set_flag(meta, &mut self.skip_list)
+        } else if meta.path.is_ident("hidden") {
+            set_flag(meta, &mut self.hidden)
+        } else if meta.path.is_ident("prop") {
+            meta.parse_nested_meta(|meta| {
+                let (key, value) = parse_repr_entry(&meta, self.props.iter().map(|(k, _)| k))?;
+                let value = value.ok_or_else(|| meta.error("expected `= \"...\"`"))?;
+                self.props.push((key, value));
+                Ok(())
+            })
+        } else if meta.path.is_ident("label") {
+            // Not nested meta, as locales like "pt-BR" are not identifiers.
+            let content;
+            syn::parenthesized!(content in meta.input);
+            while !content.is_empty() {
+                let locale = if content.peek(LitStr) {
+                    content.parse()?
+                } else {
+                    let ident: Ident = content.parse()?;
+                    LitStr::new(&ident.to_string(), ident.span())
+                };
+                if self.labels.iter().any(|(l, _)| l.value() == locale.value()) {
+                    return Err(syn::Error::new(
+                        locale.span(),
+                        format_args!("duplicate locale {:?}", locale.value()),
+                    ));
+                }
+                content.parse::<syn::Token![=]>()?;
+                self.labels.push((locale, content.parse()?));
+                if !content.is_empty() {
+                    content.parse::<syn::Token![,]>()?;
+                }
+            }
+            Ok(())
+        } else if meta.path.is_ident("code") {
+            let value = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+            set_once(meta, &mut self.code, value)
+        } else if meta.path.is_ident("alias") {
+            self.aliases.push(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("group") {
+            let value: LitStr = meta.value()?.parse()?;
+            if self
+                .groups
+                .iter()
+                .any(|group| group.value() == value.value())
+            {
+                return Err(syn::Error::new(
+                    value.span(),
+                    format_args!("duplicate group `{}`", value.value()),
+                ));
+            }
+            self.groups.push(value);
+            Ok(())
+        } else {
+            Err(unknown(meta))
+        }
+    }
+
+    fn parse_serde(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("other") {
+            self.default = true;
+        } else if meta.path.is_ident("skip") {
+            self.skip_parse = true;
+            self.skip_list = true;
+        } else if meta.path.is_ident("skip_deserializing") {
+            self.skip_parse = true;
+        } else if meta.path.is_ident("skip_serializing") {
+            self.skip_list = true;
+        } else if meta.path.is_ident("alias") {
+            self.aliases.push(meta.value()?.parse()?);
+        }
+        Ok(())
+    }
+}
+
+/// Parses `name` or `name = "..."` inside `repr(...)`, `prop(...)` or
+/// `label(...)`.
+fn parse_repr_entry<'a>(
+    meta: &ParseNestedMeta,
+    mut seen: impl Iterator<Item = &'a Ident>,
+) -> syn::Result<(Ident, Option<LitStr>)> {
+    let ident = meta.path.require_ident()?.clone();
+    if seen.any(|seen| *seen == ident) {
+        return Err(duplicate(meta));
+    }
+    let value = if meta.input.peek(syn::Token![=]) {
+        Some(meta.value()?.parse()?)
+    } else {
+        None
+    };
+    Ok((ident, value))
+}
+
+/// Parses `= value` or `= "value"`, as serde accepts paths in string literals.
+fn parse_maybe_quoted<T: syn::parse::Parse>(meta: &ParseNestedMeta) -> syn::Result<T> {
+    let value = meta.value()?;
+    if value.peek(LitStr) {
+        value.parse::<LitStr>()?.parse()
+    } else {
+        value.parse()
+    }
+}
+
+fn set_once<T>(meta: &ParseNestedMeta, slot: &mut Option<T>, value: T) -> syn::Result<()> {
+    if slot.is_some() {
+        return Err(duplicate(meta));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+fn set_flag(meta: &ParseNestedMeta, flag: &mut bool) -> syn::Result<()> {
+    if *flag {
+        return Err(duplicate(meta));
+    }
+    *flag = true;
+    Ok(())
+}
+
+fn duplicate(meta: &ParseNestedMeta) -> syn::Error {
+    meta.error(format_args!("duplicate `{}`", path_str(meta)))
+}
+
+fn unknown(meta: &ParseNestedMeta) -> syn::Error {
+    meta.error(format_args!("unknown str attribute `{}`", path_str(meta)))
+}
+
+fn path_str(meta: &ParseNestedMeta) -> String {
+    meta.path.to_token_stream().to_string().replace(' ', "")
+}
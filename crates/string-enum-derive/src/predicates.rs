@@ -0,0 +1,89 @@
+//! `is_*` predicate methods, enabled with `#[str(predicates)]`.
+
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use quote::format_ident;
+use quote::quote;
+use syn::Ident;
+
+use crate::case::RenameRule;
+use crate::Enum;
+
+/// A generated predicate: its method name, the variants it is true for and
+/// the span to report problems at.
+pub(crate) struct Predicate<'a> {
+    pub name: String,
+    pub variants: Vec<&'a Ident>,
+    pub span: Span,
+}
+
+impl Enum {
+    /// One predicate per variant, then one per group in order of first use.
+    pub(crate) fn predicates(&self) -> Vec<Predicate<'_>> {
+        let mut predicates: Vec<_> = self
+            .variants
+            .iter()
+            .map(|v| Predicate {
+                name: predicate_name(&v.ident.to_string()),
+                variants: vec![&v.ident],
+                span: v.ident.span(),
+            })
+            .collect();
+
+        let mut groups: Vec<(String, Predicate)> = Vec::new();
+        for variant in &self.variants {
+            for group in &variant.options.groups {
+                match groups.iter_mut().find(|(name, _)| *name == group.value()) {
+                    Some((_, predicate)) => predicate.variants.push(&variant.ident),
+                    None => groups.push((group.value(), Predicate {
+                        name: predicate_name(&group.value()),
+                        variants: vec![&variant.ident],
+                        span: group.span(),
+                    })),
+                }
+            }
+        }
+
+        predicates.extend(groups.into_iter().map(|(_, predicate)| predicate));
+        predicates
+    }
+
+    pub(crate) fn derive_predicates(&self) -> TokenStream {
+        let Enum { vis, ident, .. } = self;
+
+        if !self.options.predicates {
+            return TokenStream::new();
+        }
+
+        let methods = self.predicates().into_iter().map(|predicate| {
+            let Predicate { name, variants, .. } = predicate;
+            let method = format_ident!("{}", name);
+            let doc = match variants.as_slice() {
+                [variant] => format!("Returns whether this is [`Self::{variant}`]."),
+                variants => {
+                    let variants: Vec<_> =
+                        variants.iter().map(|v| format!("[`Self::{v}`]")).collect();
+                    format!("Returns whether this is one of {}.", variants.join(", "))
+                }
+            };
+
+            quote! {
+                #[doc = #doc]
+                #vis const fn #method(&self) -> bool {
+                    ::core::matches!(*self, #(Self::#variants)|*)
+                }
+            }
+        });
+
+        quote! {
+            impl #ident {
+                #(#methods)*
+            }
+        }
+    }
+}
+
+/// `is_` followed by `name` in snake_case.
+fn predicate_name(name: &str) -> String {
+    format!("is_{}", RenameRule::SnakeCase.apply_to_variant(name))
+}
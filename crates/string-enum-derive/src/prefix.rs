@@ -0,0 +1,102 @@
+//! Longest-match prefix parsing over the deserialize names and aliases,
+//! compiled into a byte trie of nested `match` expressions.
+
+use std::collections::BTreeMap;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+use crate::Enum;
+
+#[derive(Default)]
+struct Trie<'a> {
+    variant: Option<&'a Ident>,
+    children: BTreeMap<u8, Trie<'a>>,
+}
+
+impl<'a> Trie<'a> {
+    /// Inserts `name`. If two names end on the same node, which only happens
+    /// when they differ in ASCII case, the first one wins.
+    fn insert(&mut self, name: &[u8], variant: &'a Ident) {
+        match name.split_first() {
+            Some((byte, rest)) => self
+                .children
+                .entry(*byte)
+                .or_default()
+                .insert(rest, variant),
+            None => {
+                self.variant.get_or_insert(variant);
+            }
+        }
+    }
+
+    /// Reports the variant of this node, if any, then descends into the child
+    /// matching the byte at `depth`, lowercased if `fold` is set.
+    fn to_tokens(&self, depth: usize, fold: bool) -> TokenStream {
+        let report = self
+            .variant
+            .map(|variant| quote!(f(Self::#variant, #depth);));
+
+        let children = (!self.children.is_empty()).then(|| {
+            let next = depth + 1;
+            let arms = self.children.iter().map(|(byte, child)| {
+                let child = child.to_tokens(next, fold);
+                quote!(::core::option::Option::Some(#byte) => { #child })
+            });
+            let byte = if fold {
+                quote!(bytes.get(#depth).map(u8::to_ascii_lowercase))
+            } else {
+                quote!(bytes.get(#depth).copied())
+            };
+            quote! {
+                match #byte {
+                    #(#arms,)*
+                    _ => {}
+                }
+            }
+        });
+
+        quote!(#report #children)
+    }
+}
+
+impl Enum {
+    /// Implements `StringEnum::prefix_matches`.
+    pub(crate) fn derive_prefix_matches(&self) -> TokenStream {
+        let mut exact = Trie::default();
+        let mut folded = Trie::default();
+        for variant in self.parsed_variants() {
+            for name in self.accepted_names(variant) {
+                exact.insert(name.as_bytes(), &variant.ident);
+                folded.insert(name.to_ascii_lowercase().as_bytes(), &variant.ident);
+            }
+        }
+
+        // `bytes` and `f` go unused if there is nothing to descend into or
+        // report.
+        let unused = match (exact.variant, exact.children.is_empty()) {
+            (None, true) => quote!(let _ = (bytes, f);),
+            (Some(_), true) => quote!(let _ = bytes;),
+            (_, false) => TokenStream::new(),
+        };
+        let exact = exact.to_tokens(0, false);
+        let folded = folded.to_tokens(0, true);
+
+        quote! {
+            fn prefix_matches(
+                input: &str,
+                ignore_ascii_case: bool,
+                f: &mut dyn ::core::ops::FnMut(Self, usize),
+            ) {
+                let bytes = ::core::primitive::str::as_bytes(input);
+                #unused
+                if ignore_ascii_case {
+                    #folded
+                } else {
+                    #exact
+                }
+            }
+        }
+    }
+}
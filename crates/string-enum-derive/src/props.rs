@@ -0,0 +1,88 @@
+//! Per-variant properties and groups, declared with `#[str(prop(...))]` and
+//! `#[str(group = "...")]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::Enum;
+
+impl Enum {
+    pub(crate) fn derive_props(&self) -> TokenStream {
+        let Enum {
+            vis,
+            ident,
+            variants,
+            ..
+        } = self;
+
+        let has_props = variants.iter().any(|v| !v.options.props.is_empty());
+        let has_groups = variants.iter().any(|v| !v.options.groups.is_empty());
+
+        if !has_props && !has_groups {
+            return TokenStream::new();
+        }
+
+        let krate = self.crate_path();
+        let remainder = self.match_remainder();
+
+        let props = has_props.then(|| {
+            let arms = variants.iter().map(|v| {
+                let ident = &v.ident;
+                let props = v.options.props.iter().map(|(key, value)| {
+                    let key = key.to_string();
+                    quote!((#key, #value))
+                });
+                quote!(Self::#ident => &[#(#props,)*])
+            });
+
+            quote! {
+                /// Returns the `prop(key = "value")` pairs of the variant, in
+                /// declaration order.
+                #vis fn props(&self) -> &'static [(&'static str, &'static str)] {
+                    match *self {
+                        #(#arms,)*
+                        #remainder
+                    }
+                }
+
+                /// Returns the value of the property `key` of the variant, if any.
+                #vis fn get_prop(&self, key: &str) -> ::core::option::Option<&'static str> {
+                    let mut props = self.props().iter();
+                    ::core::iter::Iterator::find_map(&mut props, |&(k, v)| (k == key).then_some(v))
+                }
+            }
+        });
+
+        let groups = has_groups.then(|| {
+            let arms = variants.iter().map(|v| {
+                let ident = &v.ident;
+                let groups = &v.options.groups;
+                quote!(Self::#ident => &[#(#groups,)*])
+            });
+
+            quote! {
+                /// Returns the groups the variant belongs to, in declaration order.
+                #vis fn groups(&self) -> &'static [&'static str] {
+                    match *self {
+                        #(#arms,)*
+                        #remainder
+                    }
+                }
+
+                /// Iterates over the variants in `group`, in the order of
+                /// `StringEnum::VARIANTS`.
+                #vis fn in_group(group: &str) -> impl ::core::iter::Iterator<Item = Self> + '_ {
+                    let variants = <Self as #krate::StringEnum>::VARIANTS.iter().copied();
+                    ::core::iter::Iterator::filter(variants, move |v| v.groups().contains(&group))
+                }
+            }
+        });
+
+        quote! {
+            impl #ident {
+                #props
+                #groups
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Regular expressions matching the deserialize names and aliases.
+
+use crate::Enum;
+
+/// Matches nothing, for enums without parsed variants.
+const NEVER: &str = r"[^\s\S]";
+
+impl Enum {
+    /// Returns the unanchored and anchored regular expressions matching
+    /// exactly the deserialize names and aliases. Longer names come first so
+    /// that leftmost-first engines prefer `<=` over `<`.
+    pub(crate) fn regex(&self) -> (String, String) {
+        let mut names: Vec<_> = self
+            .parsed_variants()
+            .flat_map(|v| self.accepted_names(v))
+            .collect();
+        // Stable, so names of equal length keep their declaration order.
+        names.sort_by_key(|name| std::cmp::Reverse(name.len()));
+
+        let alternation = if names.is_empty() {
+            NEVER.to_owned()
+        } else {
+            names
+                .iter()
+                .map(|name| escape(name))
+                .collect::<Vec<_>>()
+                .join("|")
+        };
+
+        (format!("(?:{alternation})"), format!("^(?:{alternation})$"))
+    }
+}
+
+/// Escapes the meta characters shared by common regex syntaxes, including
+/// the `regex` crate, PCRE and ECMAScript. Only the escaping is portable;
+/// see `StringEnum::REGEX_ANCHORED` for how `$` differs between engines.
+/// Characters that are only special inside classes or with flags, like `-` and
+/// `#`, stay unescaped so that the pattern remains valid in engines that reject
+/// unnecessary escapes.
+fn escape(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        if r"\.+*?()|[]{}^$".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
@@ -1,3 +1,5 @@
+use proc_macro2::Span;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RenameAttr<T> {
     /// `= "value"`
@@ -39,64 +41,7 @@ impl<T: ParseLitStr> syn::parse::Parse for RenameAttr<T> {
             let value = T::parse_lit_str(lit)?;
             Ok(Self::Both(value))
         } else if lookahead.peek(syn::token::Paren) {
-            struct State<T> {
-                serialize: Option<T>,
-                deserialize: Option<T>,
-            }
-
-            impl<T: ParseLitStr> State<T> {
-                const fn new() -> Self {
-                    Self {
-                        serialize: None,
-                        deserialize: None,
-                    }
-                }
-
-                fn parse(&mut self, input: syn::parse::ParseStream) -> syn::Result<()> {
-                    let ident = input.parse::<syn::Ident>()?;
-                    let _eq = input.parse::<syn::Token![=]>()?;
-                    let lit = input.parse()?;
-                    let value = T::parse_lit_str(lit)?;
-
-                    // Clone the string only once. <Ident as PartialEq<&str>> clones the string
-                    // every time.
-                    let ident_str = ident.to_string();
-                    match ident_str.as_str() {
-                        "serialize" if self.serialize.is_some() => {
-                            Err(syn::Error::new(ident.span(), "duplicate `serialize`"))
-                        }
-                        "serialize" => {
-                            self.serialize = Some(value);
-                            Ok(())
-                        }
-                        "deserialize" if self.deserialize.is_some() => {
-                            Err(syn::Error::new(ident.span(), "duplicate `deserialize`"))
-                        }
-                        "deserialize" => {
-                            self.deserialize = Some(value);
-                            Ok(())
-                        }
-                        _ => Err(syn::Error::new(
-                            ident.span(),
-                            "expected `serialize` or `deserialize`",
-                        )),
-                    }
-                }
-
-                fn into_attr(self) -> syn::Result<RenameAttr<T>> {
-                    match (self.serialize, self.deserialize) {
-                        (Some(serialize), Some(deserialize)) => Ok(RenameAttr::ExplicitBoth {
-                            serialize,
-                            deserialize,
-                        }),
-                        (Some(value), None) => Ok(RenameAttr::SerializeOnly(value)),
-                        (None, Some(value)) => Ok(RenameAttr::DeserializeOnly(value)),
-                        (None, None) => unreachable!(),
-                    }
-                }
-            }
-
-            let mut state = State::new();
+            let mut state = RenameParts::new();
 
             let tokens;
             let _paren = syn::parenthesized!(tokens in input);
@@ -108,10 +53,9 @@ impl<T: ParseLitStr> syn::parse::Parse for RenameAttr<T> {
                 state.parse(&tokens)?;
             }
 
-            if tokens.is_empty() {
-                state.into_attr()
-            } else {
-                Err(tokens.error("expected `serialize` or `deserialize`"))
+            match state.into_attr() {
+                Some(attr) if tokens.is_empty() => Ok(attr),
+                _ => Err(tokens.error("expected `serialize` or `deserialize`")),
             }
         } else {
             Err(lookahead.error())
@@ -119,6 +63,116 @@ impl<T: ParseLitStr> syn::parse::Parse for RenameAttr<T> {
     }
 }
 
+/// The `serialize` and `deserialize` halves of a rename, collected one at a
+/// time, possibly across several attributes.
+pub struct RenameParts<T> {
+    serialize: Option<(T, Span)>,
+    deserialize: Option<(T, Span)>,
+}
+
+impl<T: ParseLitStr> RenameParts<T> {
+    fn parse(&mut self, input: syn::parse::ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<syn::Ident>()?;
+        let _eq = input.parse::<syn::Token![=]>()?;
+        let lit = input.parse()?;
+        self.set(&ident, T::parse_lit_str(lit)?)
+    }
+
+    /// Parses `serialize = "..."` or `deserialize = "..."` from a nested meta
+    /// list. Returns `false` without consuming anything for any other key.
+    pub fn parse_nested(&mut self, meta: &syn::meta::ParseNestedMeta) -> syn::Result<bool> {
+        let Some(ident) = meta.path.get_ident() else {
+            return Ok(false);
+        };
+        if ident != "serialize" && ident != "deserialize" {
+            return Ok(false);
+        }
+        let lit = meta.value()?.parse()?;
+        self.set(ident, T::parse_lit_str(lit)?)?;
+        Ok(true)
+    }
+
+    fn set(&mut self, ident: &syn::Ident, value: T) -> syn::Result<()> {
+        // Clone the string only once. <Ident as PartialEq<&str>> clones the string
+        // every time.
+        let ident_str = ident.to_string();
+        match ident_str.as_str() {
+            "serialize" => set_half(&mut self.serialize, "serialize", ident.span(), value),
+            "deserialize" => set_half(&mut self.deserialize, "deserialize", ident.span(), value),
+            _ => Err(syn::Error::new(
+                ident.span(),
+                "expected `serialize` or `deserialize`",
+            )),
+        }
+    }
+}
+
+impl<T> RenameParts<T> {
+    pub const fn new() -> Self {
+        Self {
+            serialize: None,
+            deserialize: None,
+        }
+    }
+
+    /// Adds the halves given by `attr`, which came from the attribute at
+    /// `span`. Giving the same half twice is an error, as it is for serde.
+    pub fn merge(&mut self, span: Span, attr: RenameAttr<T>) -> syn::Result<()>
+    where
+        T: Clone,
+    {
+        let (serialize, deserialize) = match attr {
+            RenameAttr::Both(value) => (Some(value.clone()), Some(value)),
+            RenameAttr::SerializeOnly(value) => (Some(value), None),
+            RenameAttr::DeserializeOnly(value) => (None, Some(value)),
+            RenameAttr::ExplicitBoth {
+                serialize,
+                deserialize,
+            } => (Some(serialize), Some(deserialize)),
+        };
+        if let Some(value) = serialize {
+            set_half(&mut self.serialize, "serialize", span, value)?;
+        }
+        if let Some(value) = deserialize {
+            set_half(&mut self.deserialize, "deserialize", span, value)?;
+        }
+        Ok(())
+    }
+
+    /// Returns `None` if neither half was given.
+    pub fn into_attr(self) -> Option<RenameAttr<T>> {
+        self.or(Self::new())
+    }
+
+    /// Takes each half from `self` if given, otherwise from `fallback`.
+    pub fn or(self, fallback: Self) -> Option<RenameAttr<T>> {
+        let serialize = self.serialize.or(fallback.serialize);
+        let deserialize = self.deserialize.or(fallback.deserialize);
+        match (serialize, deserialize) {
+            (Some((serialize, _)), Some((deserialize, _))) => Some(RenameAttr::ExplicitBoth {
+                serialize,
+                deserialize,
+            }),
+            (Some((value, _)), None) => Some(RenameAttr::SerializeOnly(value)),
+            (None, Some((value, _))) => Some(RenameAttr::DeserializeOnly(value)),
+            (None, None) => None,
+        }
+    }
+}
+
+fn set_half<T>(slot: &mut Option<(T, Span)>, half: &str, span: Span, value: T) -> syn::Result<()> {
+    if let Some((_, first)) = slot {
+        let mut error = syn::Error::new(span, format!("duplicate `{half}` rename"));
+        error.combine(syn::Error::new(
+            *first,
+            format!("`{half}` rename first given here"),
+        ));
+        return Err(error);
+    }
+    *slot = Some((value, span));
+    Ok(())
+}
+
 pub trait ParseLitStr: Sized {
     fn parse_lit_str(lit: syn::LitStr) -> syn::Result<Self>;
 }
@@ -141,3 +195,10 @@ impl ParseLitStr for crate::case::RenameRule {
         Self::from_str(&s).map_err(|e| syn::Error::new(lit.span(), e))
     }
 }
+
+impl ParseLitStr for crate::charset::Charset {
+    fn parse_lit_str(lit: syn::LitStr) -> syn::Result<Self> {
+        let s = lit.value();
+        Self::from_str(&s).map_err(|e| syn::Error::new(lit.span(), e))
+    }
+}
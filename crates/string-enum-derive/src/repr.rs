@@ -0,0 +1,100 @@
+//! Additional named string representations, declared with
+//! `#[str(repr(...))]`.
+
+use proc_macro2::TokenStream;
+use quote::format_ident;
+use quote::quote;
+use syn::Ident;
+
+use crate::options::Repr;
+use crate::Enum;
+use crate::EnumVariant;
+
+impl Enum {
+    /// The value of `repr` for `variant`: an explicit value, the repr's rename
+    /// rule, or else the serialize name.
+    pub(crate) fn repr_name(&self, variant: &EnumVariant, repr: &Repr) -> String {
+        let explicit = variant
+            .options
+            .reprs
+            .iter()
+            .find(|(ident, _)| *ident == repr.ident);
+
+        if let Some((_, value)) = explicit {
+            value.value()
+        } else if let Some(rule) = repr.rule {
+            rule.apply_to_variant(variant.ident.to_string())
+        } else {
+            self.serialize_name(variant)
+        }
+    }
+
+    pub(crate) fn derive_reprs(&self) -> TokenStream {
+        let Enum {
+            vis,
+            ident,
+            options,
+            variants,
+            ..
+        } = self;
+
+        if options.reprs.is_empty() {
+            return TokenStream::new();
+        }
+
+        let krate = self.crate_path();
+
+        let remainder = self.match_remainder();
+
+        let methods = options.reprs.iter().map(|repr| {
+            let as_repr = as_repr(&repr.ident);
+            let from_repr = format_ident!("from_{}_str", repr.ident);
+            let as_doc = format!("Returns the `{}` representation of the variant.", repr.ident);
+            let from_doc = format!("Parses the `{}` representation of a variant.", repr.ident);
+
+            let as_arms = variants.iter().map(|v| {
+                let ident = &v.ident;
+                let name = self.repr_name(v, repr);
+                quote!(Self::#ident => #name)
+            });
+            let names: Vec<_> = self
+                .parsed_variants()
+                .map(|v| self.repr_name(v, repr))
+                .collect();
+            let invalid_variant = self.invalid_variant_error(|v| self.repr_name(v, repr));
+            let from_arms = self.parsed_variants().zip(&names).map(|(v, name)| {
+                let ident = &v.ident;
+                quote!(#name => ::core::result::Result::Ok(Self::#ident))
+            });
+
+            quote! {
+                #[doc = #as_doc]
+                #vis fn #as_repr(&self) -> &'static str {
+                    match *self {
+                        #(#as_arms,)*
+                        #remainder
+                    }
+                }
+
+                #[doc = #from_doc]
+                #vis fn #from_repr(s: &str) -> ::core::result::Result<Self, #krate::InvalidVariantError> {
+                    match s {
+                        #(#from_arms,)*
+                        _ => ::core::result::Result::Err(#invalid_variant),
+                    }
+                }
+            }
+        });
+
+        quote! {
+            impl #ident {
+                #(#methods)*
+            }
+        }
+    }
+}
+
+/// The name of the accessor method for a repr.
+pub(crate) fn as_repr(repr: &Ident) -> Ident {
+    format_ident!("as_{}", repr)
+}
@@ -0,0 +1,68 @@
+//! Stable numeric ids, enabled with `#[str(stable_id)]`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::Enum;
+use crate::EnumVariant;
+
+const FNV_OFFSET_BASIS: u32 = 0x811c_9dc5;
+const FNV_PRIME: u32 = 0x0100_0193;
+
+impl Enum {
+    /// The explicit `code` of `variant`, or else the FNV-1a hash of its
+    /// serialize name. Must agree with `string_enum::stable_id`.
+    pub(crate) fn stable_id(&self, variant: &EnumVariant) -> u32 {
+        variant.options.code.unwrap_or_else(|| {
+            self.serialize_name(variant)
+                .bytes()
+                .fold(FNV_OFFSET_BASIS, |hash, byte| {
+                    (hash ^ u32::from(byte)).wrapping_mul(FNV_PRIME)
+                })
+        })
+    }
+
+    pub(crate) fn derive_stable_ids(&self) -> TokenStream {
+        let Enum {
+            vis,
+            ident,
+            options,
+            variants,
+            ..
+        } = self;
+
+        if !options.stable_id {
+            return TokenStream::new();
+        }
+
+        let ids: Vec<_> = variants.iter().map(|v| self.stable_id(v)).collect();
+        let to_arms = variants.iter().zip(&ids).map(|(v, id)| {
+            let ident = &v.ident;
+            quote!(Self::#ident => #id)
+        });
+        let from_arms = variants.iter().zip(&ids).map(|(v, id)| {
+            let ident = &v.ident;
+            quote!(#id => ::core::option::Option::Some(Self::#ident))
+        });
+
+        quote! {
+            impl #ident {
+                /// Returns the stable id of the variant, which stays the same
+                /// as long as its serialize name or `code` does.
+                #vis const fn stable_id(self) -> u32 {
+                    match self {
+                        #(#to_arms,)*
+                    }
+                }
+
+                /// Returns the variant with the given stable id, if any.
+                #vis const fn from_stable_id(id: u32) -> ::core::option::Option<Self> {
+                    match id {
+                        #(#from_arms,)*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,868 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use proc_macro2::Span;
+use syn::Error;
+use syn::Ident;
+
+use crate::charset::Charset;
+use crate::options::EnumOptions;
+use crate::predicates::Predicate;
+use crate::Enum;
+use crate::EnumVariant;
+
+impl Enum {
+    /// Checks the resolved variant names for problems that would otherwise
+    /// only surface as unreachable `match` arms or surprising runtime
+    /// behavior.
+    pub(crate) fn validate(&self) -> syn::Result<()> {
+        let mut errors = Errors::default();
+
+        self.check_unique(&mut errors, "serialize", |this, variant| {
+            Some([this.serialize_name(variant)])
+        });
+        self.check_unique(&mut errors, "deserialize", |this, variant| {
+            variant.parsed().then(|| this.accepted_names(variant))
+        });
+        self.check_charset(&mut errors);
+        self.check_data(&mut errors);
+        self.check_reprs(&mut errors);
+        self.check_labels(&mut errors);
+        self.check_stable_ids(&mut errors);
+        if self.options.predicates {
+            self.check_predicates(&mut errors);
+        }
+        self.check_fallback(&mut errors);
+        self.check_error(&mut errors);
+        if self.options.strict_roundtrip {
+            self.check_roundtrip(&mut errors);
+        }
+
+        errors.finish()
+    }
+
+    /// Enums with data-carrying variants only get `as_str`, `Display` and
+    /// optionally `FromStr`, so options building on `StringEnum` or on
+    /// constructing variants do not apply, unless they have a `kind`.
+    fn check_data(&self, errors: &mut Errors) {
+        let options = &self.options;
+
+        if !self.has_data_variants() {
+            if options.default_fields {
+                errors.push(Error::new(
+                    self.ident.span(),
+                    "`default_fields` requires a variant with fields",
+                ));
+            }
+            if let Some(kind) = &options.kind {
+                errors.push(Error::new(
+                    kind.span(),
+                    "`kind` requires a variant with fields",
+                ));
+            }
+            return;
+        }
+
+        // Every other option applies to the companion enum.
+        if let Some(kind) = &options.kind {
+            if options.default_fields {
+                errors.push(Error::new(
+                    kind.span(),
+                    "`kind` cannot be combined with `default_fields`",
+                ));
+            }
+            return;
+        }
+
+        let unsupported = [
+            (!options.reprs.is_empty(), "repr"),
+            (options.alternate.is_some(), "alternate"),
+            (options.impl_default, "impl_default"),
+            (options.stable_id, "stable_id"),
+            (options.predicates, "predicates"),
+            (options.fallback_locale.is_some(), "fallback_locale"),
+        ];
+        for (_, option) in unsupported.iter().filter(|(used, _)| *used) {
+            errors.push(Error::new(
+                self.ident.span(),
+                format!("`{option}` is not supported on enums with data-carrying variants"),
+            ));
+        }
+
+        if !options.default_fields && !options.no_from_str {
+            let from_str_only = [
+                (options.error.is_some(), "error"),
+                (options.error_type.is_some(), "error_type"),
+                (options.expecting.is_some(), "expecting"),
+                (options.max_listed.is_some(), "max_listed"),
+            ];
+            for (_, option) in from_str_only.iter().filter(|(used, _)| *used) {
+                errors.push(Error::new(
+                    self.ident.span(),
+                    format!(
+                        "`{option}` requires `default_fields` on enums with data-carrying variants"
+                    ),
+                ));
+            }
+        }
+
+        for variant in &self.variants {
+            let options = &variant.options;
+            let unsupported = [
+                (options.default, "default"),
+                (!options.reprs.is_empty(), "repr"),
+                (!options.props.is_empty(), "prop"),
+                (!options.groups.is_empty(), "group"),
+                (!options.labels.is_empty(), "label"),
+                (options.code.is_some(), "code"),
+            ];
+            for (_, option) in unsupported.iter().filter(|(used, _)| *used) {
+                errors.push(Error::new(
+                    variant.ident.span(),
+                    format!("`{option}` is not supported on enums with data-carrying variants"),
+                ));
+            }
+        }
+    }
+
+    /// The custom error options only make sense together with `FromStr`.
+    fn check_error(&self, errors: &mut Errors) {
+        let EnumOptions {
+            no_from_str,
+            error,
+            error_with,
+            error_type,
+            ..
+        } = &self.options;
+
+        if let (Some(error_with), None) = (error_with, error) {
+            errors.push(Error::new_spanned(
+                error_with,
+                "`error_with` requires `error`",
+            ));
+        }
+        if let (Some(_), Some(error_type)) = (error, error_type) {
+            errors.push(Error::new(
+                error_type.span(),
+                "`error_type` cannot be combined with `error`",
+            ));
+        }
+        if *no_from_str {
+            if let Some(error) = error {
+                errors.push(Error::new_spanned(
+                    error,
+                    "`error` has no effect with `no_from_str`",
+                ));
+            }
+            if let Some(error_type) = error_type {
+                errors.push(Error::new(
+                    error_type.span(),
+                    "`error_type` has no effect with `no_from_str`",
+                ));
+            }
+        }
+    }
+
+    /// At most one variant can be the fallback, and `impl_default` needs one.
+    fn check_fallback(&self, errors: &mut Errors) {
+        let mut fallbacks = self.variants.iter().filter(|v| v.options.default);
+
+        match fallbacks.next() {
+            Some(first) => {
+                for variant in fallbacks {
+                    errors.push(Error::new(
+                        variant.ident.span(),
+                        format!(
+                            "multiple fallback variants, `{}` is already the default",
+                            first.ident
+                        ),
+                    ));
+                }
+            }
+            None if self.options.impl_default => {
+                errors.push(Error::new(
+                    self.ident.span(),
+                    "`impl_default` requires a variant marked #[str(default)]",
+                ));
+            }
+            None => {}
+        }
+    }
+
+    /// Every repr used by a variant or by `alternate` must be declared on the
+    /// enum, and each repr must be unique like the primary names. A repr may
+    /// not be named `str`, as its accessor would be `as_str`.
+    fn check_reprs(&self, errors: &mut Errors) {
+        let reprs = &self.options.reprs;
+        let declared = |ident: &Ident| reprs.iter().any(|repr| repr.ident == *ident);
+
+        if let Some(alternate) = &self.options.alternate {
+            if self.options.no_display {
+                errors.push(Error::new(
+                    alternate.span(),
+                    "`alternate` has no effect with `no_display`",
+                ));
+            } else if !reprs.iter().any(|repr| repr.ident == alternate.value()) {
+                errors.push(Error::new(
+                    alternate.span(),
+                    format!("unknown repr {:?}", alternate.value()),
+                ));
+            }
+        }
+
+        for variant in &self.variants {
+            for (ident, _) in &variant.options.reprs {
+                if !declared(ident) {
+                    errors.push(Error::new(
+                        ident.span(),
+                        format!("unknown repr `{ident}`, declare it with #[str(repr({ident}))] on the enum"),
+                    ));
+                }
+            }
+        }
+
+        for repr in reprs {
+            // `as_str` would shadow `StringEnum::as_str` as an inherent method.
+            if repr.ident == "str" {
+                errors.push(Error::new(
+                    repr.ident.span(),
+                    "repr `str` would shadow `StringEnum::as_str`",
+                ));
+            }
+
+            let kind = format!("`{}` repr", repr.ident);
+            self.check_unique(errors, &kind, |this, variant| {
+                Some([this.repr_name(variant, repr)])
+            });
+        }
+    }
+
+    /// The fallback locale must be used, and every variant needs a label for
+    /// it so that `label` never falls back to nothing.
+    fn check_labels(&self, errors: &mut Errors) {
+        let Some(fallback) = &self.options.fallback_locale else {
+            return;
+        };
+
+        if !self.locales().contains(&fallback.value()) {
+            errors.push(Error::new(
+                fallback.span(),
+                format!(
+                    "unknown locale {:?}, no variant has a label for it",
+                    fallback.value()
+                ),
+            ));
+            return;
+        }
+
+        for variant in &self.variants {
+            if !variant
+                .options
+                .labels
+                .iter()
+                .any(|(locale, _)| locale.value() == fallback.value())
+            {
+                errors.push(Error::new(
+                    variant.ident.span(),
+                    format!(
+                        "missing label for the fallback locale {:?}",
+                        fallback.value()
+                    ),
+                ));
+            }
+        }
+    }
+
+    /// Stable ids must be unique, and `code` only makes sense with them.
+    fn check_stable_ids(&self, errors: &mut Errors) {
+        if !self.options.stable_id {
+            for variant in &self.variants {
+                if variant.options.code.is_some() {
+                    errors.push(Error::new(
+                        variant.ident.span(),
+                        "`code` requires #[str(stable_id)] on the enum",
+                    ));
+                }
+            }
+            return;
+        }
+
+        let mut seen = HashMap::<u32, &Ident>::new();
+        for variant in &self.variants {
+            let id = self.stable_id(variant);
+            match seen.entry(id) {
+                Entry::Occupied(entry) => {
+                    let first = entry.get();
+                    errors.push(Error::new(
+                        variant.ident.span(),
+                        format!("duplicate stable id {id}, also used by `{first}`"),
+                    ));
+                    errors.push(Error::new(
+                        first.span(),
+                        format!("stable id {id} first used here"),
+                    ));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(&variant.ident);
+                }
+            }
+        }
+    }
+
+    /// Predicate names must be identifiers, and distinct so that a group does
+    /// not clash with a variant.
+    fn check_predicates(&self, errors: &mut Errors) {
+        let mut seen = HashMap::<String, Span>::new();
+        for Predicate { name, span, .. } in self.predicates() {
+            if syn::parse_str::<Ident>(&name).is_err() {
+                errors.push(Error::new(
+                    span,
+                    format!("`{name}` is not a valid predicate name"),
+                ));
+                continue;
+            }
+            match seen.entry(name) {
+                Entry::Occupied(entry) => {
+                    errors.push(Error::new(
+                        span,
+                        format!("duplicate predicate `{}`", entry.key()),
+                    ));
+                    errors.push(Error::new(
+                        *entry.get(),
+                        format!("predicate `{}` first generated here", entry.key()),
+                    ));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(span);
+                }
+            }
+        }
+    }
+
+    /// Every serialize name must be the deserialize name or an alias of the
+    /// same variant. Variants that are never parsed are exempt.
+    fn check_roundtrip(&self, errors: &mut Errors) {
+        for variant in self.parsed_variants() {
+            let name = self.serialize_name(variant);
+            let parsed = self
+                .parsed_variants()
+                .find(|other| self.accepted_names(other).contains(&name));
+
+            let message = match parsed {
+                Some(other) if other.ident == variant.ident => continue,
+                Some(other) => format!("serialize name {name:?} parses as `{}`", other.ident),
+                None => format!(
+                    "serialize name {name:?} does not parse, the deserialize name is {:?}",
+                    self.deserialize_name(variant),
+                ),
+            };
+            errors.push(Error::new(variant.ident.span(), message));
+        }
+    }
+
+    /// Applies the `charset`, `non_empty` and `max_len` constraints to every
+    /// serialize and deserialize name.
+    fn check_charset(&self, errors: &mut Errors) {
+        let EnumOptions {
+            charset,
+            non_empty,
+            max_len,
+            ..
+        } = self.options;
+
+        for variant in &self.variants {
+            let serialize = self.serialize_name(variant);
+            let deserialize = self.deserialize_name(variant);
+            let mut names = if serialize == deserialize {
+                vec![("name", serialize)]
+            } else {
+                vec![
+                    ("serialize name", serialize),
+                    ("deserialize name", deserialize),
+                ]
+            };
+            names.extend(variant.options.aliases.iter().map(|a| ("alias", a.value())));
+
+            for (kind, name) in names {
+                let mut error = |message: String| {
+                    errors.push(Error::new(
+                        variant.ident.span(),
+                        format!("{kind} {name:?} {message}"),
+                    ));
+                };
+
+                if non_empty && name.is_empty() {
+                    error("is empty".to_owned());
+                }
+                if let Some(max_len) = max_len.filter(|&max_len| name.len() > max_len) {
+                    error(format!("is longer than `max_len = {max_len}`"));
+                }
+                if let Some(Err(message)) = charset.map(|charset| charset.check(&name)) {
+                    error(format!(
+                        "does not match `charset = {:?}`: {message}",
+                        charset.map(Charset::name).unwrap_or_default(),
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Two variants with the same serialize name make `as_str` impossible to
+    /// invert, and the same deserialize name makes one variant unparseable.
+    /// Variants for which `names` returns `None` are not checked.
+    fn check_unique<I: IntoIterator<Item = String>>(
+        &self,
+        errors: &mut Errors,
+        kind: &str,
+        names: impl Fn(&Self, &EnumVariant) -> Option<I>,
+    ) {
+        let mut seen = HashMap::<String, &Ident>::new();
+
+        for variant in &self.variants {
+            for name in names(self, variant).into_iter().flatten() {
+                match seen.entry(name) {
+                    Entry::Occupied(entry) => {
+                        let (name, first) = (entry.key(), entry.get());
+                        errors.push(Error::new(
+                            variant.ident.span(),
+                            format!("duplicate {kind} name {name:?}, also used by `{first}`"),
+                        ));
+                        errors.push(Error::new(
+                            first.span(),
+                            format!("{kind} name {name:?} first used here"),
+                        ));
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert(&variant.ident);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Accumulates errors so that every problem is reported in one compilation.
+#[derive(Default)]
+pub(crate) struct Errors(Option<Error>);
+
+impl Errors {
+    pub(crate) fn push(&mut self, error: Error) {
+        match &mut self.0 {
+            Some(errors) => errors.combine(error),
+            None => self.0 = Some(error),
+        }
+    }
+
+    pub(crate) fn finish(self) -> syn::Result<()> {
+        match self.0 {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use syn::parse_quote;
+    use syn::DeriveInput;
+
+    use crate::Enum;
+
+    fn errors(input: DeriveInput) -> Vec<String> {
+        match Enum::try_from(input) {
+            Ok(_) => Vec::new(),
+            Err(err) => err.into_iter().map(|e| e.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_duplicate_from_rename_all() {
+        let input: DeriveInput = parse_quote! {
+            #[str = "lowercase"]
+            enum Kind {
+                IO,
+                Io,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "duplicate serialize name \"io\", also used by `IO`",
+            "serialize name \"io\" first used here",
+            "duplicate deserialize name \"io\", also used by `IO`",
+            "deserialize name \"io\" first used here",
+        ]);
+    }
+
+    #[test]
+    fn test_duplicate_split_rename() {
+        let input: DeriveInput = parse_quote! {
+            enum Kind {
+                #[str(deserialize = "B")]
+                A,
+                B,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "duplicate deserialize name \"B\", also used by `A`",
+            "deserialize name \"B\" first used here",
+        ]);
+    }
+
+    #[test]
+    fn test_duplicate_alias() {
+        let input: DeriveInput = parse_quote! {
+            enum Switch {
+                #[str(alias = "off")]
+                On,
+                Off,
+                #[serde(alias = "off")]
+                Disabled,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "duplicate deserialize name \"off\", also used by `On`",
+            "deserialize name \"off\" first used here",
+        ]);
+    }
+
+    #[test]
+    fn test_max_listed_zero() {
+        let input: DeriveInput = parse_quote! {
+            #[str(max_listed = 0)]
+            enum Digit {
+                One,
+                Two,
+            }
+        };
+
+        assert_eq!(errors(input), ["`max_listed` must be at least 1"]);
+    }
+
+    #[test]
+    fn test_charset() {
+        let input: DeriveInput = parse_quote! {
+            #[str(charset = "url_safe", non_empty, max_len = 8)]
+            enum Segment {
+                #[str = "foo bar"]
+                FooBar,
+                #[str(serialize = "", deserialize = "baz")]
+                Baz,
+                #[str = "much-too-long"]
+                Long,
+                Ok,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "name \"foo bar\" does not match `charset = \"url_safe\"`: ' ' is not allowed",
+            "serialize name \"\" is empty",
+            "name \"much-too-long\" is longer than `max_len = 8`",
+        ]);
+    }
+
+    #[test]
+    fn test_unknown_option() {
+        let input: DeriveInput = parse_quote! {
+            #[str(charset = "emoji")]
+            enum Segment {}
+        };
+
+        assert_eq!(errors(input), [
+            "unknown charset `charset = \"emoji\"`, expected one of \"ascii\", \"ascii_graphic\", \"url_safe\", \"identifier\"",
+        ]);
+    }
+
+    #[test]
+    fn test_strict_roundtrip() {
+        let input: DeriveInput = parse_quote! {
+            #[str(serialize = "camelCase", deserialize = "snake_case", strict_roundtrip)]
+            enum Mixed {
+                #[str(deserialize = "selectOne")]
+                SelectOne,
+                SelectTwo,
+                #[str(serialize = "select_two", deserialize = "three")]
+                SelectThree,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "serialize name \"selectTwo\" does not parse, the deserialize name is \"select_two\"",
+            "serialize name \"select_two\" parses as `SelectTwo`",
+        ]);
+    }
+
+    #[test]
+    fn test_reprs() {
+        let input: DeriveInput = parse_quote! {
+            #[str(repr(short = "UPPERCASE", label), alternate = "name")]
+            enum Country {
+                #[str(repr(short = "US", label = "United States"))]
+                UnitedStates,
+                #[str(repr(lable = "Uruguay"))]
+                Us,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "unknown repr \"name\"",
+            "unknown repr `lable`, declare it with #[str(repr(lable))] on the enum",
+            "duplicate `short` repr name \"US\", also used by `UnitedStates`",
+            "`short` repr name \"US\" first used here",
+        ]);
+
+        let input: DeriveInput = parse_quote! {
+            #[str(repr(str = "UPPERCASE"))]
+            enum Letter {
+                Alpha,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "repr `str` would shadow `StringEnum::as_str`"
+        ]);
+    }
+
+    #[test]
+    fn test_fallback() {
+        let input: DeriveInput = parse_quote! {
+            enum Status {
+                #[str(default)]
+                Unknown,
+                #[serde(other)]
+                Other,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "multiple fallback variants, `Unknown` is already the default",
+        ]);
+
+        let input: DeriveInput = parse_quote! {
+            #[str(impl_default)]
+            enum Status {
+                Active,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "`impl_default` requires a variant marked #[str(default)]",
+        ]);
+    }
+
+    #[test]
+    fn test_skip_parse_duplicates() {
+        let input: DeriveInput = parse_quote! {
+            #[str(strict_roundtrip)]
+            enum Status {
+                #[str(deserialize = "active")]
+                Active,
+                #[str(skip_parse, deserialize = "active")]
+                Legacy,
+                #[serde(skip_deserializing)]
+                #[str(skip_list)]
+                Reserved,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "serialize name \"Active\" does not parse, the deserialize name is \"active\"",
+        ]);
+    }
+
+    #[test]
+    fn test_duplicate_rename_halves() {
+        let input: DeriveInput = parse_quote! {
+            enum Kind {
+                #[serde(rename = "a")]
+                #[serde(rename(deserialize = "b"))]
+                A,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "duplicate `deserialize` rename",
+            "`deserialize` rename first given here",
+        ]);
+
+        let input: DeriveInput = parse_quote! {
+            enum Kind {
+                #[str(serialize = "a")]
+                #[str = "b"]
+                A,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "duplicate `serialize` rename",
+            "`serialize` rename first given here",
+        ]);
+    }
+
+    #[test]
+    fn test_duplicate_props_and_groups() {
+        let input: DeriveInput = parse_quote! {
+            enum Unit {
+                #[str(prop(symbol = "m", symbol = "M"))]
+                Meter,
+            }
+        };
+
+        assert_eq!(errors(input), ["duplicate `symbol`"]);
+
+        let input: DeriveInput = parse_quote! {
+            enum Unit {
+                #[str(group = "si")]
+                #[str(group = "si")]
+                Meter,
+            }
+        };
+
+        assert_eq!(errors(input), ["duplicate group `si`"]);
+    }
+
+    #[test]
+    fn test_labels() {
+        let input: DeriveInput = parse_quote! {
+            #[str(fallback_locale = "fr")]
+            enum Status {
+                #[str(label(en = "Done"))]
+                Done,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "unknown locale \"fr\", no variant has a label for it",
+        ]);
+
+        let input: DeriveInput = parse_quote! {
+            #[str(fallback_locale = "en")]
+            enum Status {
+                #[str(label(en = "Done", de = "Erledigt"))]
+                Done,
+                #[str(label(de = "Fehlgeschlagen"))]
+                Failed,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "missing label for the fallback locale \"en\"",
+        ]);
+    }
+
+    #[test]
+    fn test_duplicate_locale() {
+        let input: DeriveInput = parse_quote! {
+            enum Status {
+                #[str(label("pt-BR" = "Feito"))]
+                #[str(label(en = "Done", "pt-BR" = "Pronto"))]
+                Done,
+            }
+        };
+
+        assert_eq!(errors(input), ["duplicate locale \"pt-BR\""]);
+    }
+
+    #[test]
+    fn test_stable_ids() {
+        let input: DeriveInput = parse_quote! {
+            #[str(stable_id)]
+            enum Shape {
+                Circle,
+                #[str(code = 7)]
+                Square,
+                #[str(code = 7)]
+                Triangle,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "duplicate stable id 7, also used by `Square`",
+            "stable id 7 first used here",
+        ]);
+
+        let input: DeriveInput = parse_quote! {
+            enum Shape {
+                #[str(code = 7)]
+                Circle,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "`code` requires #[str(stable_id)] on the enum",
+        ]);
+    }
+
+    #[test]
+    fn test_predicates() {
+        let input: DeriveInput = parse_quote! {
+            #[str(predicates)]
+            enum Status {
+                #[str(group = "done")]
+                Failed,
+                #[str(group = "in-flight")]
+                Running,
+                Done,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "duplicate predicate `is_done`",
+            "predicate `is_done` first generated here",
+            "`is_in-flight` is not a valid predicate name",
+        ]);
+    }
+
+    #[test]
+    fn test_data_variants() {
+        let input: DeriveInput = parse_quote! {
+            #[str(stable_id, expecting = "an event")]
+            enum Event {
+                Created { id: u32 },
+                #[str(default)]
+                Deleted(u32),
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "`stable_id` is not supported on enums with data-carrying variants",
+            "`expecting` requires `default_fields` on enums with data-carrying variants",
+            "`default` is not supported on enums with data-carrying variants",
+        ]);
+
+        let input: DeriveInput = parse_quote! {
+            #[str(default_fields)]
+            enum Event {
+                Created,
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "`default_fields` requires a variant with fields"
+        ]);
+    }
+
+    #[test]
+    fn test_kind() {
+        let input: DeriveInput = parse_quote! {
+            #[str(kind = EventKind, default_fields)]
+            enum Event {
+                Created { id: u32 },
+            }
+        };
+
+        assert_eq!(errors(input), [
+            "`kind` cannot be combined with `default_fields`"
+        ]);
+
+        let input: DeriveInput = parse_quote! {
+            #[str(kind = EventKind)]
+            enum Event {
+                Created,
+            }
+        };
+
+        assert_eq!(errors(input), ["`kind` requires a variant with fields"]);
+    }
+}
@@ -1,65 +1,182 @@
+use core::fmt::Formatter;
+use core::fmt::Result;
+
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub struct InvalidVariantError {
     variants: &'static [&'static str],
+    type_name: Option<&'static str>,
+    expecting: Option<&'static str>,
+    max_listed: Option<usize>,
 }
 
 impl InvalidVariantError {
     pub const fn new(variants: &'static [&'static str]) -> Self {
-        Self { variants }
+        Self {
+            variants,
+            type_name: None,
+            expecting: None,
+            max_listed: None,
+        }
+    }
+
+    /// Names the enum that failed to parse in the error message, e.g.
+    /// "invalid Color variant".
+    pub const fn with_type_name(self, type_name: &'static str) -> Self {
+        Self {
+            type_name: Some(type_name),
+            ..self
+        }
+    }
+
+    /// Describes what was expected, e.g. "a log level", instead of listing
+    /// the variants.
+    pub const fn with_expecting(self, expecting: &'static str) -> Self {
+        Self {
+            expecting: Some(expecting),
+            ..self
+        }
+    }
+
+    /// Lists at most `max_listed` variants, summarizing the rest as "... and N
+    /// more". With 0, no variants are listed at all.
+    pub const fn with_max_listed(self, max_listed: usize) -> Self {
+        Self {
+            max_listed: Some(max_listed),
+            ..self
+        }
+    }
+
+    /// The names that would have been accepted, excluding hidden variants.
+    pub const fn variants(&self) -> &'static [&'static str] {
+        self.variants
+    }
+
+    pub const fn type_name(&self) -> Option<&'static str> {
+        self.type_name
+    }
+
+    pub const fn expecting(&self) -> Option<&'static str> {
+        self.expecting
+    }
+
+    /// Formats the error message with the given [`Wording`], e.g. to localize
+    /// it. The `Display` implementation uses [`English`].
+    pub fn display_with<'a>(&'a self, wording: &'a dyn Wording) -> impl core::fmt::Display + 'a {
+        WithWording(self, wording)
     }
 }
 
 impl core::fmt::Display for InvalidVariantError {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if self.variants.is_empty() {
-            f.pad("invalid variant")
-        } else {
-            write!(f, "invalid variant, {}", Expected(self.variants))
-        }
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        WithWording(self, &English).fmt(f)
     }
 }
 
 impl core::fmt::Debug for InvalidVariantError {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         f.write_str("InvalidVariantError")
     }
 }
 
 impl core::error::Error for InvalidVariantError {}
 
-struct Expected(&'static [&'static str]);
-
-impl core::fmt::Display for Expected {
-    #[inline]
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        match self.0 {
-            [] => unreachable!(),
-            [single] => write!(f, "expected {single}"),
-            many => write!(f, "expected {}", OneOf(many)),
+/// The phrasing of an [`InvalidVariantError`] message.
+///
+/// Every method has a default producing the English message, e.g. "invalid
+/// variant, expected one of: a, b or c". Override them to localize the message
+/// and pass the wording to [`InvalidVariantError::display_with`].
+pub trait Wording {
+    /// Writes the start of the message.
+    fn invalid(&self, f: &mut Formatter<'_>, type_name: Option<&str>) -> Result {
+        match type_name {
+            Some(type_name) => write!(f, "invalid {type_name} variant"),
+            None => f.write_str("invalid variant"),
         }
     }
+
+    /// Writes what was expected: a description or the only variant.
+    fn expected(&self, f: &mut Formatter<'_>, expected: &str) -> Result {
+        write!(f, ", expected {expected}")
+    }
+
+    /// Writes the lead-in to a list of variants.
+    fn expected_one_of(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(", expected one of: ")
+    }
+
+    /// Separates the items of the list.
+    fn separator(&self) -> &str {
+        ", "
+    }
+
+    /// Separates the last two items of the list, including any spaces.
+    fn last_separator(&self) -> &str {
+        " or "
+    }
+
+    /// Writes the summary of the variants left out of the list.
+    fn and_more(&self, f: &mut Formatter<'_>, count: usize) -> Result {
+        write!(f, ", ... and {count} more")
+    }
 }
 
-struct OneOf(&'static [&'static str]);
+/// The default [`Wording`].
+pub struct English;
+
+impl Wording for English {}
+
+struct WithWording<'a>(&'a InvalidVariantError, &'a dyn Wording);
+
+impl core::fmt::Display for WithWording<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let WithWording(error, wording) = *self;
 
-impl core::fmt::Display for OneOf {
-    #[inline]
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str("one of: ")?;
+        wording.invalid(f, error.type_name)?;
 
-        oxford_comma(f, self.0, "or")
+        if let Some(expecting) = error.expecting {
+            return wording.expected(f, expecting);
+        }
+
+        let max_listed = error.max_listed.unwrap_or(usize::MAX);
+        let (listed, more) = match error.variants.split_at_checked(max_listed) {
+            Some((listed, rest)) => (listed, rest.len()),
+            None => (error.variants, 0),
+        };
+
+        match (listed, more) {
+            ([], _) => Ok(()),
+            ([single], 0) => wording.expected(f, single),
+            (listed, 0) => {
+                wording.expected_one_of(f)?;
+                oxford_comma(f, listed, wording.separator(), wording.last_separator())
+            }
+            (listed, more) => {
+                wording.expected_one_of(f)?;
+                for (i, item) in listed.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(wording.separator())?;
+                    }
+                    f.write_str(item)?;
+                }
+                wording.and_more(f, more)
+            }
+        }
     }
 }
 
 /// Writes a list of items with an Oxford comma.
 #[inline]
-fn oxford_comma(w: &mut dyn core::fmt::Write, items: &[&str], joiner: &str) -> core::fmt::Result {
+fn oxford_comma(
+    w: &mut dyn core::fmt::Write,
+    items: &[&str],
+    separator: &str,
+    last_separator: &str,
+) -> Result {
     let [many @ .., n_sub_1, n_sub_0] = items else {
-        // SAFETY: `Expected` does not call this function with less than 2 items.
-        unsafe { core::hint::unreachable_unchecked() };
+        unreachable!("`WithWording` does not call this function with less than 2 items");
     };
     for item in many {
-        write!(w, "{item}, ")?;
+        write!(w, "{item}{separator}")?;
     }
-    write!(w, "{n_sub_1} {joiner} {n_sub_0}")
+    write!(w, "{n_sub_1}{last_separator}{n_sub_0}")
 }
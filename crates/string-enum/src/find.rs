@@ -0,0 +1,85 @@
+use core::iter::FusedIterator;
+
+use crate::is_word_boundary;
+use crate::StringEnum;
+
+/// An iterator over the non-overlapping occurrences of deserialize names in a
+/// haystack, created by [`StringEnum::find_iter`].
+///
+/// At each position the longest matching name wins, and the search resumes
+/// after it.
+#[derive(Debug, Clone)]
+pub struct FindIter<'h, T> {
+    haystack: &'h str,
+    pos: usize,
+    ignore_ascii_case: bool,
+    words: bool,
+    variant: core::marker::PhantomData<T>,
+}
+
+impl<'h, T: StringEnum> FindIter<'h, T> {
+    pub(crate) fn new(haystack: &'h str) -> Self {
+        Self {
+            haystack,
+            pos: 0,
+            ignore_ascii_case: false,
+            words: false,
+            variant: core::marker::PhantomData,
+        }
+    }
+
+    /// Matches names regardless of ASCII case.
+    pub fn ignore_ascii_case(self) -> Self {
+        Self {
+            ignore_ascii_case: true,
+            ..self
+        }
+    }
+
+    /// Only matches names that neither start nor end within a run of ASCII
+    /// word characters (alphanumeric or `_`).
+    pub fn words(self) -> Self {
+        Self {
+            words: true,
+            ..self
+        }
+    }
+}
+
+impl<T: StringEnum> Iterator for FindIter<'_, T> {
+    type Item = (usize, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self {
+            haystack,
+            ignore_ascii_case,
+            words,
+            ..
+        } = *self;
+        let bytes = haystack.as_bytes();
+
+        while let Some(rest) = haystack.get(self.pos..).filter(|rest| !rest.is_empty()) {
+            let start = self.pos;
+            let mut longest = None;
+            if !words || is_word_boundary(bytes, start) {
+                T::prefix_matches(rest, ignore_ascii_case, &mut |variant, len| {
+                    if len > 0 && (!words || is_word_boundary(bytes, start + len)) {
+                        longest = Some((variant, len));
+                    }
+                });
+            }
+
+            match longest {
+                Some((variant, len)) => {
+                    self.pos += len;
+                    return Some((start, variant));
+                }
+                None => self.pos += rest.chars().next().map_or(1, char::len_utf8),
+            }
+        }
+
+        None
+    }
+}
+
+impl<T: StringEnum> FusedIterator for FindIter<'_, T> {}
@@ -0,0 +1,174 @@
+use core::iter::FusedIterator;
+
+use crate::StringEnum;
+
+/// A shell-style wildcard pattern.
+///
+/// - `*` matches any sequence of characters, including none.
+/// - `?` matches any single character.
+/// - `[abc]` and `[a-z]` match one character from a set or range, `[!abc]` or
+///   `[^abc]` one character outside of it. A `]` right after the opening
+///   bracket is part of the set.
+/// - `\` matches the following character literally.
+/// - A leading `!` negates the whole pattern.
+///
+/// An unclosed `[` matches itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Glob<'p> {
+    pattern: &'p str,
+    negated: bool,
+}
+
+impl<'p> Glob<'p> {
+    pub fn new(pattern: &'p str) -> Self {
+        match pattern.strip_prefix('!') {
+            Some(pattern) => Self {
+                pattern,
+                negated: true,
+            },
+            None => Self {
+                pattern,
+                negated: false,
+            },
+        }
+    }
+
+    pub fn is_match(&self, name: &str) -> bool {
+        matches(self.pattern, name) != self.negated
+    }
+}
+
+/// Matches `name` against `pattern`, retrying from the last `*` on mismatch.
+fn matches(pattern: &str, name: &str) -> bool {
+    let (mut p, mut n) = (pattern, name);
+    // The pattern after the last `*` and the name it is currently matched at.
+    let mut star: Option<(&str, &str)> = None;
+
+    loop {
+        let mut chars = p.chars();
+        let matched = match (chars.next(), n.chars().next()) {
+            (Some('*'), _) => {
+                p = chars.as_str();
+                star = Some((p, n));
+                continue;
+            }
+            (None, None) => return true,
+            (Some(token), Some(c)) => match_char(token, chars.as_str(), c),
+            (_, None) | (None, Some(_)) => None,
+        };
+
+        match (matched, star) {
+            (Some(rest), _) => {
+                p = rest;
+                n = skip_char(n);
+            }
+            (None, Some((star_p, star_n))) if !star_n.is_empty() => {
+                let star_n = skip_char(star_n);
+                star = Some((star_p, star_n));
+                (p, n) = (star_p, star_n);
+            }
+            (None, _) => return false,
+        }
+    }
+}
+
+/// Matches `c` against the pattern token starting with `token`, followed by
+/// `rest`. Returns the pattern after the token on success.
+fn match_char(token: char, rest: &str, c: char) -> Option<&str> {
+    match token {
+        '?' => Some(rest),
+        '[' => match match_class(rest, c) {
+            Some((true, rest)) => Some(rest),
+            Some((false, _)) => None,
+            None => (c == '[').then_some(rest),
+        },
+        '\\' => {
+            let mut chars = rest.chars();
+            match chars.next() {
+                Some(escaped) => (c == escaped).then_some(chars.as_str()),
+                None => (c == '\\').then_some(rest),
+            }
+        }
+        token => (c == token).then_some(rest),
+    }
+}
+
+/// Matches `c` against the class starting after `[`. Returns whether it
+/// matched and the pattern after the closing `]`, or `None` if the class is
+/// not closed.
+fn match_class(class: &str, c: char) -> Option<(bool, &str)> {
+    let (negated, class) = match class.strip_prefix(['!', '^']) {
+        Some(class) => (true, class),
+        None => (false, class),
+    };
+
+    let mut chars = class.chars();
+    let mut matched = false;
+    let mut first = true;
+    loop {
+        let lo = match chars.next()? {
+            ']' if !first => return Some((matched != negated, chars.as_str())),
+            '\\' => chars.next()?,
+            lo => lo,
+        };
+        first = false;
+
+        let mut ahead = chars.clone();
+        let hi = match (ahead.next(), ahead.clone().next()) {
+            (Some('-'), Some(hi)) if hi != ']' => {
+                ahead.next();
+                chars = ahead;
+                hi
+            }
+            _ => lo,
+        };
+        matched |= (lo..=hi).contains(&c);
+    }
+}
+
+fn skip_char(s: &str) -> &str {
+    let mut chars = s.chars();
+    chars.next();
+    chars.as_str()
+}
+
+/// An iterator over the variants whose name matches a [`Glob`], created by
+/// [`StringEnum::matching`].
+#[derive(Debug, Clone)]
+pub struct Matching<'p, T: 'static> {
+    glob: Glob<'p>,
+    variants: core::slice::Iter<'static, T>,
+}
+
+impl<'p, T: StringEnum> Matching<'p, T> {
+    pub(crate) fn new(pattern: &'p str) -> Self {
+        Self {
+            glob: Glob::new(pattern),
+            variants: T::VARIANTS.iter(),
+        }
+    }
+}
+
+impl<T: StringEnum> Iterator for Matching<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let glob = self.glob;
+        self.variants
+            .by_ref()
+            .copied()
+            .find(|v| glob.is_match(v.as_str()))
+    }
+}
+
+impl<T: StringEnum> DoubleEndedIterator for Matching<'_, T> {
+    fn next_back(&mut self) -> Option<T> {
+        let glob = self.glob;
+        self.variants
+            .by_ref()
+            .copied()
+            .rfind(|v| glob.is_match(v.as_str()))
+    }
+}
+
+impl<T: StringEnum> FusedIterator for Matching<'_, T> {}
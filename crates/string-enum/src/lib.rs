@@ -1,16 +1,258 @@
 #![no_std]
 
+// Lets the derive's `::string_enum` paths resolve inside this crate.
+extern crate self as string_enum;
+
 mod error;
+mod find;
+mod glob;
+#[cfg(feature = "nom")]
+pub mod nom;
+mod stable_id;
+#[cfg(feature = "winnow")]
+pub mod winnow;
 
 #[cfg(feature = "derive")]
 pub use string_enum_derive::StringEnum;
 
+pub use crate::error::English;
 pub use crate::error::InvalidVariantError;
+pub use crate::error::Wording;
+pub use crate::find::FindIter;
+pub use crate::glob::Glob;
+pub use crate::glob::Matching;
+pub use crate::stable_id::stable_id;
 
 pub trait StringEnum: Copy + Sized + 'static {
     const VARIANTS: &'static [Self];
 
+    /// The name returned by [`as_str`](Self::as_str) for each of
+    /// [`VARIANTS`](Self::VARIANTS), in the same order.
+    const SERIALIZE_NAMES: &'static [&'static str];
+
+    /// Every name accepted by `FromStr`, in declaration order, not counting
+    /// aliases. Variants that are skipped from parsing are not included, so
+    /// this does not necessarily line up with [`VARIANTS`](Self::VARIANTS).
+    const DESERIALIZE_NAMES: &'static [&'static str];
+
+    /// The length in bytes of the longest name returned by
+    /// [`as_str`](Self::as_str), or 0 for an empty enum.
+    ///
+    /// The default only considers [`SERIALIZE_NAMES`](Self::SERIALIZE_NAMES),
+    /// so implementations with variants skipped from the list should
+    /// override it, as the derive does.
+    const MAX_LEN: usize = max_len(Self::SERIALIZE_NAMES);
+
+    /// The length in bytes of the shortest name returned by
+    /// [`as_str`](Self::as_str), or 0 for an empty enum.
+    ///
+    /// Like [`MAX_LEN`](Self::MAX_LEN), the default only considers
+    /// [`SERIALIZE_NAMES`](Self::SERIALIZE_NAMES).
+    const MIN_LEN: usize = min_len(Self::SERIALIZE_NAMES);
+
+    /// A regular expression matching exactly the deserialize names and
+    /// aliases, as a non-capturing group of escaped names, longest first.
+    /// Matches nothing for an enum without parsed variants.
+    ///
+    /// These are the names `FromStr` accepts, unless the enum has a fallback
+    /// variant: `FromStr` then accepts any input, while this still only
+    /// matches the names.
+    const REGEX: &'static str;
+
+    /// [`REGEX`](Self::REGEX) anchored with `^` and `$`.
+    ///
+    /// In the `regex` crate, RE2 and ECMAScript, `$` only matches at the end
+    /// of the input. In PCRE, Python and Java it also matches before a
+    /// trailing newline, so that `"name\n"` validates there; anchor
+    /// [`REGEX`](Self::REGEX) with the engine's end-of-input assertion, such
+    /// as `\z` or `\Z`, instead.
+    const REGEX_ANCHORED: &'static str;
+
     fn as_str(&self) -> &'static str;
+
+    /// Parses either a deserialize or a serialize name, so that the output of
+    /// [`as_str`](Self::as_str) parses back even when the two differ. Variants
+    /// that are skipped from parsing are excluded, so their names do not parse.
+    ///
+    /// If a string is the deserialize name of one variant and the serialize
+    /// name of another, the deserialize name wins, matching `FromStr`.
+    fn parse_any(s: &str) -> Result<Self, InvalidVariantError>;
+
+    /// Calls `f` with every variant whose deserialize name or alias is a
+    /// prefix of `input`, along with the length of that name, shortest first.
+    /// With `ignore_ascii_case`, names are compared regardless of ASCII case.
+    ///
+    /// Backs the prefix parsers; not part of the public API. The default scans
+    /// [`DESERIALIZE_NAMES`](Self::DESERIALIZE_NAMES), so it does not know
+    /// about aliases. The derive overrides it with a byte trie.
+    #[doc(hidden)]
+    fn prefix_matches(input: &str, ignore_ascii_case: bool, f: &mut dyn FnMut(Self, usize)) {
+        let bytes = input.as_bytes();
+        for len in 0..=max_len(Self::DESERIALIZE_NAMES).min(bytes.len()) {
+            let prefix = &bytes[..len];
+            // As with the trie, the first of names differing only in ASCII
+            // case wins.
+            let name = Self::DESERIALIZE_NAMES.iter().find(|name| {
+                let name = name.as_bytes();
+                if ignore_ascii_case {
+                    name.eq_ignore_ascii_case(prefix)
+                } else {
+                    name == prefix
+                }
+            });
+            if let Some(variant) = name.and_then(|name| Self::parse_any(name).ok()) {
+                f(variant, len);
+            }
+        }
+    }
+
+    /// Parses the variant with the longest deserialize name at the start of
+    /// `input`, returning it with the rest of the input. For example, `"<=x"`
+    /// parses as `<=` rather than `<` if both are variants.
+    fn parse_prefix(input: &str) -> Option<(Self, &str)> {
+        let mut longest = None;
+        Self::prefix_matches(input, false, &mut |variant, len| {
+            longest = Some((variant, len))
+        });
+        longest.map(|(variant, len)| (variant, input.split_at(len).1))
+    }
+
+    /// Like [`parse_prefix`](Self::parse_prefix), but a name ending in an ASCII
+    /// word character (alphanumeric or `_`) only matches if it is not followed
+    /// by another, so that `"int"` does not parse as `in` followed by `"t"`.
+    fn parse_word_prefix(input: &str) -> Option<(Self, &str)> {
+        let mut longest = None;
+        Self::prefix_matches(input, false, &mut |variant, len| {
+            if is_word_boundary(input.as_bytes(), len) {
+                longest = Some((variant, len));
+            }
+        });
+        longest.map(|(variant, len)| (variant, input.split_at(len).1))
+    }
+
+    /// Iterates over the non-overlapping occurrences of deserialize names in
+    /// `haystack`, yielding each with its byte offset. See [`FindIter`] for
+    /// options.
+    fn find_iter(haystack: &str) -> FindIter<'_, Self> {
+        FindIter::new(haystack)
+    }
+
+    /// Iterates over the variants of [`VARIANTS`](Self::VARIANTS) whose
+    /// [`as_str`](Self::as_str) matches the wildcard `pattern`, e.g. `"db.*"`
+    /// or `"!internal_*"`. See [`Glob`] for the syntax.
+    fn matching(pattern: &str) -> Matching<'_, Self> {
+        Matching::new(pattern)
+    }
+
+    /// The index of the variant in [`VARIANTS`](Self::VARIANTS), or `None` if
+    /// it is skipped from the list.
+    fn position(&self) -> Option<usize>;
+
+    /// Returns [`as_str`](Self::as_str) as a fixed-size buffer, filling the
+    /// remainder with `pad`. Fails to compile if `N` is less than
+    /// [`MAX_LEN`](Self::MAX_LEN).
+    fn to_padded<const N: usize>(&self, pad: u8) -> [u8; N] {
+        const { assert!(N >= Self::MAX_LEN, "buffer is shorter than `MAX_LEN`") };
+        self.to_truncated(pad)
+    }
+
+    /// Like [`to_padded`](Self::to_padded), but cuts names longer than `N`
+    /// bytes short instead of failing to compile. The cut is not necessarily
+    /// on a `char` boundary.
+    fn to_truncated<const N: usize>(&self, pad: u8) -> [u8; N] {
+        let name = self.as_str().as_bytes();
+        let len = name.len().min(N);
+        let mut buf = [pad; N];
+        buf[..len].copy_from_slice(&name[..len]);
+        buf
+    }
+
+    /// Iterates over [`VARIANTS`](Self::VARIANTS).
+    fn iter() -> core::iter::Copied<core::slice::Iter<'static, Self>> {
+        Self::VARIANTS.iter().copied()
+    }
+
+    /// The first of [`VARIANTS`](Self::VARIANTS), if any.
+    fn first() -> Option<Self> {
+        Self::VARIANTS.first().copied()
+    }
+
+    /// The last of [`VARIANTS`](Self::VARIANTS), if any.
+    fn last() -> Option<Self> {
+        Self::VARIANTS.last().copied()
+    }
+
+    /// The variant following this one in [`VARIANTS`](Self::VARIANTS), or
+    /// `None` for the last one and for variants skipped from the list.
+    fn next(&self) -> Option<Self> {
+        self.position()
+            .and_then(|i| Self::VARIANTS.get(i + 1))
+            .copied()
+    }
+
+    /// The variant preceding this one in [`VARIANTS`](Self::VARIANTS), or
+    /// `None` for the first one and for variants skipped from the list.
+    fn prev(&self) -> Option<Self> {
+        self.position()
+            .and_then(|i| i.checked_sub(1))
+            .and_then(|i| Self::VARIANTS.get(i))
+            .copied()
+    }
+
+    /// Like [`next`](Self::next), but wraps around from the last variant to
+    /// the first. Variants skipped from the list are returned unchanged.
+    fn next_cyclic(&self) -> Self {
+        match self.position() {
+            Some(i) => Self::VARIANTS[(i + 1) % Self::VARIANTS.len()],
+            None => *self,
+        }
+    }
+
+    /// Like [`prev`](Self::prev), but wraps around from the first variant to
+    /// the last. Variants skipped from the list are returned unchanged.
+    fn prev_cyclic(&self) -> Self {
+        match self.position() {
+            Some(0) => Self::VARIANTS[Self::VARIANTS.len() - 1],
+            Some(i) => Self::VARIANTS[i - 1],
+            None => *self,
+        }
+    }
+}
+
+/// Whether `at` does not split a run of ASCII word characters in `bytes`.
+fn is_word_boundary(bytes: &[u8], at: usize) -> bool {
+    let is_word = |i: Option<usize>| {
+        i.and_then(|i| bytes.get(i))
+            .is_some_and(|&b| b.is_ascii_alphanumeric() || b == b'_')
+    };
+    !(is_word(at.checked_sub(1)) && is_word(Some(at)))
+}
+
+const fn max_len(names: &[&str]) -> usize {
+    let mut max = 0;
+    let mut i = 0;
+    while i < names.len() {
+        if names[i].len() > max {
+            max = names[i].len();
+        }
+        i += 1;
+    }
+    max
+}
+
+const fn min_len(names: &[&str]) -> usize {
+    if names.is_empty() {
+        return 0;
+    }
+    let mut min = usize::MAX;
+    let mut i = 0;
+    while i < names.len() {
+        if names[i].len() < min {
+            min = names[i].len();
+        }
+        i += 1;
+    }
+    min
 }
 
 #[cfg(all(test, feature = "derive"))]
@@ -26,8 +268,9 @@ mod test {
     use serde::Serialize;
     use string_enum::StringEnum;
 
-    use crate as string_enum;
+    use crate::Glob;
     use crate::InvalidVariantError;
+    use crate::Wording;
 
     #[test]
     fn test_empty_enum() {
@@ -125,6 +368,998 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_strict_roundtrip() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str(serialize = "camelCase", deserialize = "camelCase", strict_roundtrip)]
+        enum StrictRoundtrip {
+            #[str(serialize = "one", deserialize = "one")]
+            SelectOne,
+            SelectTwo,
+        }
+
+        test_enum(
+            &[
+                TestCase::new(StrictRoundtrip::SelectOne, "one", "one"),
+                TestCase::new(StrictRoundtrip::SelectTwo, "selectTwo", "selectTwo"),
+            ],
+            "invalid variant, expected one of: one or selectTwo",
+        );
+    }
+
+    #[test]
+    fn test_reprs() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str = "snake_case"]
+        #[str(repr(short = "lowercase", label), alternate = "label")]
+        enum Country {
+            #[str(repr(short = "us", label = "United States"))]
+            UnitedStates,
+            Canada,
+        }
+
+        test_enum(
+            &[
+                TestCase::new(Country::UnitedStates, "united_states", "united_states"),
+                TestCase::new(Country::Canada, "canada", "canada"),
+            ],
+            "invalid variant, expected one of: united_states or canada",
+        );
+
+        assert_eq!(Country::UnitedStates.as_short(), "us");
+        assert_eq!(Country::Canada.as_short(), "canada");
+        assert_eq!(Country::UnitedStates.as_label(), "United States");
+        assert_eq!(Country::Canada.as_label(), "canada");
+
+        assert_eq!(Country::from_short_str("us"), Ok(Country::UnitedStates));
+        assert_eq!(
+            Country::from_label_str("United States"),
+            Ok(Country::UnitedStates)
+        );
+        let err = Country::from_short_str("united_states").unwrap_err();
+        assert_eq!(
+            alloc::format!("{err}"),
+            "invalid variant, expected one of: us or canada"
+        );
+
+        assert_eq!(alloc::format!("{}", Country::UnitedStates), "united_states");
+        assert_eq!(
+            alloc::format!("{:#}", Country::UnitedStates),
+            "United States"
+        );
+        assert_eq!(alloc::format!("{:>8}", Country::Canada), "  canada");
+    }
+
+    #[test]
+    fn test_fallback() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str(serialize = "lowercase", deserialize = "lowercase", impl_default)]
+        enum WithFallback {
+            Active,
+            #[str(default)]
+            Unknown,
+        }
+
+        assert_eq!(WithFallback::from_str("active"), Ok(WithFallback::Active));
+        assert_eq!(WithFallback::from_str("unknown"), Ok(WithFallback::Unknown));
+        assert_eq!(WithFallback::from_str("retired"), Ok(WithFallback::Unknown));
+        assert_eq!(WithFallback::default(), WithFallback::Unknown);
+
+        let err = WithFallback::from_str_strict("retired").unwrap_err();
+        assert_eq!(
+            alloc::format!("{err}"),
+            "invalid variant, expected one of: active or unknown"
+        );
+        assert!(WithFallback::parse_any("retired").is_err());
+    }
+
+    #[test]
+    fn test_serde_other() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum, Serialize, Deserialize)]
+        #[serde(rename_all = "lowercase", deny_unknown_fields)]
+        enum WithSerdeOther {
+            #[serde(alias = "on", rename = "enabled")]
+            Enabled,
+            #[serde(other)]
+            Other,
+        }
+
+        assert_eq!(
+            WithSerdeOther::from_str("enabled"),
+            Ok(WithSerdeOther::Enabled)
+        );
+        assert_eq!(WithSerdeOther::from_str("on"), Ok(WithSerdeOther::Enabled));
+        assert_eq!(WithSerdeOther::parse_any("on"), Ok(WithSerdeOther::Enabled));
+        assert_eq!(WithSerdeOther::DESERIALIZE_NAMES, ["enabled", "other"]);
+        assert_eq!(
+            WithSerdeOther::from_str("disabled"),
+            Ok(WithSerdeOther::Other)
+        );
+        assert_eq!(WithSerdeOther::Other.as_str(), "other");
+    }
+
+    #[test]
+    fn test_core_default() {
+        #[derive(Debug, Clone, Copy, PartialEq, Default, StringEnum)]
+        enum WithCoreDefault {
+            Alpha,
+            #[default]
+            Beta,
+        }
+
+        assert_eq!(
+            WithCoreDefault::from_str("Alpha"),
+            Ok(WithCoreDefault::Alpha)
+        );
+        assert_eq!(
+            WithCoreDefault::from_str("Gamma"),
+            Ok(WithCoreDefault::Beta)
+        );
+        assert_eq!(WithCoreDefault::default(), WithCoreDefault::Beta);
+    }
+
+    #[test]
+    fn test_skip() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str = "lowercase"]
+        enum WithSkip {
+            Alpha,
+            #[str(skip)]
+            Unknown,
+            #[str(skip_parse)]
+            Legacy,
+            #[str(skip_list)]
+            Hidden,
+            Omega,
+        }
+
+        assert_eq!(WithSkip::VARIANTS, [
+            WithSkip::Alpha,
+            WithSkip::Legacy,
+            WithSkip::Omega,
+        ]);
+        assert_eq!(WithSkip::SERIALIZE_NAMES, ["alpha", "legacy", "omega"]);
+        assert_eq!(WithSkip::DESERIALIZE_NAMES, ["alpha", "hidden", "omega"]);
+
+        assert_eq!(WithSkip::Unknown.as_str(), "unknown");
+        assert_eq!(WithSkip::Legacy.as_str(), "legacy");
+        assert_eq!(WithSkip::from_str("hidden"), Ok(WithSkip::Hidden));
+        assert!(WithSkip::from_str("unknown").is_err());
+        assert!(WithSkip::parse_any("legacy").is_err());
+
+        let err = WithSkip::from_str("legacy").unwrap_err();
+        assert_eq!(
+            alloc::format!("{err}"),
+            "invalid variant, expected one of: alpha, hidden or omega"
+        );
+    }
+
+    #[test]
+    fn test_serde_skip() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum, Serialize, Deserialize)]
+        enum WithSerdeSkip {
+            Alpha,
+            #[serde(skip)]
+            Internal,
+            #[serde(skip_serializing)]
+            ReadOnly,
+            #[serde(skip_deserializing)]
+            WriteOnly,
+        }
+
+        assert_eq!(WithSerdeSkip::VARIANTS, [
+            WithSerdeSkip::Alpha,
+            WithSerdeSkip::WriteOnly,
+        ]);
+        assert_eq!(WithSerdeSkip::DESERIALIZE_NAMES, ["Alpha", "ReadOnly"]);
+        assert_eq!(WithSerdeSkip::Internal.as_str(), "Internal");
+    }
+
+    #[test]
+    fn test_cfg_variants() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        enum WithCfg {
+            Alpha,
+            #[cfg(not(test))]
+            Disabled,
+            #[cfg(test)]
+            Enabled,
+        }
+
+        test_enum(
+            &[
+                TestCase::new(WithCfg::Alpha, "Alpha", "Alpha"),
+                TestCase::new(WithCfg::Enabled, "Enabled", "Enabled"),
+            ],
+            "invalid variant, expected one of: Alpha or Enabled",
+        );
+    }
+
+    #[test]
+    fn test_cfg_attr() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum, Serialize)]
+        #[cfg_attr(test, serde(rename_all = "kebab-case"))]
+        #[cfg_attr(not(test), str = "UPPERCASE")]
+        enum WithCfgAttr {
+            FirstItem,
+            #[cfg_attr(test, str(serialize = "two", skip_list))]
+            MiddleEntry,
+            #[cfg_attr(not(test), str = "three")]
+            LastValue,
+        }
+
+        assert_eq!(WithCfgAttr::VARIANTS, [
+            WithCfgAttr::FirstItem,
+            WithCfgAttr::LastValue
+        ]);
+        assert_eq!(WithCfgAttr::SERIALIZE_NAMES, ["first-item", "last-value"]);
+        assert_eq!(WithCfgAttr::DESERIALIZE_NAMES, [
+            "first-item",
+            "middle-entry",
+            "last-value"
+        ]);
+        assert_eq!(WithCfgAttr::MiddleEntry.as_str(), "two");
+    }
+
+    #[test]
+    fn test_with_split_serde_rules() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum, Serialize, Deserialize)]
+        #[serde(rename_all(serialize = "camelCase"))]
+        #[serde(rename_all(deserialize = "snake_case"))]
+        enum WithSplitSerdeRules {
+            #[serde(rename(serialize = "one"))]
+            #[serde(alias = "uno", rename(deserialize = "1"))]
+            SelectOne,
+            #[str(serialize = "two")]
+            #[serde(rename = "TWO")]
+            SelectTwo,
+        }
+
+        test_enum(
+            &[
+                TestCase::new(WithSplitSerdeRules::SelectOne, "one", "1"),
+                TestCase::new(WithSplitSerdeRules::SelectTwo, "two", "TWO"),
+            ],
+            "invalid variant, expected one of: 1 or TWO",
+        );
+    }
+
+    #[test]
+    fn test_crate_path() {
+        mod facade {
+            pub use crate as strings;
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str(crate = "facade::strings")]
+        enum WithCratePath {
+            Alpha,
+        }
+
+        macro_rules! facade_enum {
+            ($(#[$meta:meta])* enum $name:ident { $($variant:ident,)* }) => {
+                $(#[$meta])*
+                #[derive($crate::StringEnum)]
+                #[str(crate = $crate)]
+                enum $name { $($variant,)* }
+            };
+        }
+
+        facade_enum! {
+            #[derive(Debug, Clone, Copy, PartialEq)]
+            enum WithMacroCratePath {
+                Beta,
+            }
+        }
+
+        assert_eq!(WithCratePath::Alpha.as_str(), "Alpha");
+        assert_eq!(WithMacroCratePath::Beta.as_str(), "Beta");
+    }
+
+    #[test]
+    fn test_no_from_str_no_display() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str(serialize = "lowercase", no_from_str, no_display)]
+        enum Level {
+            Debug,
+            Error,
+        }
+
+        impl FromStr for Level {
+            type Err = alloc::string::String;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::parse_any(&s.to_ascii_lowercase())
+                    .map_err(|err| alloc::format!("level: {err}"))
+            }
+        }
+
+        impl core::fmt::Display for Level {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(&self.as_str().to_ascii_uppercase())
+            }
+        }
+
+        assert_eq!(Level::from_str("ERROR"), Ok(Level::Error));
+        assert_eq!(
+            Level::from_str("trace"),
+            Err("level: invalid variant, expected one of: Debug or Error".into())
+        );
+        assert_eq!(alloc::format!("{}", Level::Debug), "DEBUG");
+    }
+
+    #[test]
+    fn test_custom_error() {
+        #[derive(Debug, PartialEq)]
+        struct FieldError(&'static str, InvalidVariantError);
+
+        impl From<InvalidVariantError> for FieldError {
+            fn from(err: InvalidVariantError) -> Self {
+                Self("unknown", err)
+            }
+        }
+
+        fn color_error(err: InvalidVariantError) -> FieldError {
+            FieldError("color", err)
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str(error = FieldError)]
+        enum Shape {
+            Circle,
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str(error = "FieldError", error_with = "color_error")]
+        enum Color {
+            Red,
+        }
+
+        assert_eq!(Shape::from_str("Circle"), Ok(Shape::Circle));
+        assert_eq!(
+            Shape::from_str("Square"),
+            Err(FieldError("unknown", InvalidVariantError::new(&["Circle"])))
+        );
+        assert_eq!(
+            Color::from_str("Blue"),
+            Err(FieldError("color", InvalidVariantError::new(&["Red"])))
+        );
+    }
+
+    #[test]
+    fn test_error_type() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str(error_type = ColorParseError)]
+        enum Color {
+            Red,
+            Green,
+            #[str(default)]
+            Other,
+        }
+
+        let err: ColorParseError = Color::from_str_strict("Blue").unwrap_err();
+        assert_eq!(
+            alloc::format!("{err}"),
+            "invalid Color variant, expected one of: Red, Green or Other"
+        );
+        assert_eq!(err.into_inner().type_name(), Some("Color"));
+        assert_eq!(Color::from_str("Blue"), Ok(Color::Other));
+
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str(error_type = "SizeParseError")]
+        enum Size {}
+
+        let err: SizeParseError = Size::from_str("Large").unwrap_err();
+        assert_eq!(alloc::format!("{err}"), "invalid Size variant");
+    }
+
+    #[test]
+    fn test_error_messages() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str(expecting = "a log level")]
+        enum Level {
+            Debug,
+            Info,
+        }
+
+        test_enum(
+            &[
+                TestCase::new(Level::Debug, "Debug", "Debug"),
+                TestCase::new(Level::Info, "Info", "Info"),
+            ],
+            "invalid variant, expected a log level",
+        );
+
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str(max_listed = 2)]
+        enum Digit {
+            One,
+            Two,
+            Three,
+            Four,
+            #[str(hidden)]
+            Five,
+            #[doc(hidden)]
+            Six,
+        }
+
+        let err = Digit::from_str("Zero").unwrap_err();
+        assert_eq!(err.variants(), ["One", "Two", "Three", "Four"]);
+        assert_eq!(
+            alloc::format!("{err}"),
+            "invalid variant, expected one of: One, Two, ... and 2 more"
+        );
+        assert_eq!(Digit::from_str("Five"), Ok(Digit::Five));
+        assert_eq!(Digit::from_str("Six"), Ok(Digit::Six));
+
+        struct German;
+
+        impl Wording for German {
+            fn invalid(
+                &self,
+                f: &mut core::fmt::Formatter<'_>,
+                _: Option<&str>,
+            ) -> core::fmt::Result {
+                f.write_str("ungültige Variante")
+            }
+
+            fn expected_one_of(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(", erwartet eine von: ")
+            }
+
+            fn last_separator(&self) -> &str {
+                " oder "
+            }
+        }
+
+        let err = InvalidVariantError::new(&["a", "b", "c"]);
+        assert_eq!(
+            alloc::format!("{}", err.display_with(&German)),
+            "ungültige Variante, erwartet eine von: a, b oder c"
+        );
+
+        struct Japanese;
+
+        impl Wording for Japanese {
+            fn separator(&self) -> &str {
+                "、"
+            }
+
+            fn last_separator(&self) -> &str {
+                "または"
+            }
+        }
+
+        let err = InvalidVariantError::new(&["a", "b", "c"]);
+        assert_eq!(
+            alloc::format!("{}", err.display_with(&Japanese)),
+            "invalid variant, expected one of: a、bまたはc"
+        );
+
+        let err = InvalidVariantError::new(&["a", "b"]).with_max_listed(0);
+        assert_eq!(alloc::format!("{err}"), "invalid variant");
+    }
+
+    #[test]
+    fn test_props_and_groups() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str = "lowercase"]
+        enum Status {
+            #[str(prop(color = "yellow"))]
+            Pending,
+            #[str(prop(color = "green", icon = "check"), group = "terminal")]
+            Done,
+            #[str(group = "terminal", group = "error")]
+            Failed,
+        }
+
+        assert_eq!(Status::Done.props(), [
+            ("color", "green"),
+            ("icon", "check")
+        ]);
+        assert_eq!(Status::Done.get_prop("icon"), Some("check"));
+        assert_eq!(Status::Pending.get_prop("icon"), None);
+        assert_eq!(Status::Failed.props(), []);
+
+        assert_eq!(Status::Failed.groups(), ["terminal", "error"]);
+        assert!(Status::in_group("terminal").eq([Status::Done, Status::Failed]));
+        assert!(Status::in_group("error").eq([Status::Failed]));
+        assert_eq!(Status::in_group("unknown").count(), 0);
+    }
+
+    #[test]
+    fn test_labels() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str(
+            serialize = "snake_case",
+            deserialize = "snake_case",
+            fallback_locale = "en"
+        )]
+        enum OrderState {
+            #[str(label(en = "Awaiting payment", de = "Zahlung ausstehend", ja = "支払い待ち"))]
+            AwaitingPayment,
+            #[str(label(en = "Shipped", ja = "発送済み"))]
+            Shipped,
+        }
+
+        assert_eq!(OrderState::LOCALES, ["en", "de", "ja"]);
+        assert_eq!(
+            OrderState::AwaitingPayment.label("de"),
+            Some("Zahlung ausstehend")
+        );
+        assert_eq!(OrderState::Shipped.label("ja"), Some("発送済み"));
+        assert_eq!(OrderState::Shipped.label("de"), Some("Shipped"));
+        assert_eq!(OrderState::Shipped.as_str(), "shipped");
+
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        enum Answer {
+            #[str(label(en = "Yes", de = "Ja"))]
+            Yes,
+            No,
+        }
+
+        assert_eq!(Answer::Yes.label("fr"), None);
+        assert_eq!(Answer::No.label("en"), None);
+
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str(fallback_locale = "pt-BR")]
+        enum Greeting {
+            #[str(label("pt-BR" = "Olá", "zh-Hant" = "你好", en = "Hello"))]
+            Hello,
+        }
+
+        assert_eq!(Greeting::LOCALES, ["pt-BR", "zh-Hant", "en"]);
+        assert_eq!(Greeting::Hello.label("zh-Hant"), Some("你好"));
+        assert_eq!(Greeting::Hello.label("pt_BR"), Some("Olá"));
+    }
+
+    #[test]
+    fn test_navigation() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        enum Light {
+            Red,
+            #[str(skip_list)]
+            Off,
+            Yellow,
+            Green,
+        }
+
+        assert!(Light::iter().eq([Light::Red, Light::Yellow, Light::Green]));
+        assert!(Light::iter()
+            .rev()
+            .eq([Light::Green, Light::Yellow, Light::Red]));
+        assert_eq!(Light::iter().len(), 3);
+        assert_eq!(Light::first(), Some(Light::Red));
+        assert_eq!(Light::last(), Some(Light::Green));
+
+        assert_eq!(Light::Yellow.position(), Some(1));
+        assert_eq!(Light::Off.position(), None);
+
+        assert_eq!(Light::Red.next(), Some(Light::Yellow));
+        assert_eq!(Light::Green.next(), None);
+        assert_eq!(Light::Yellow.prev(), Some(Light::Red));
+        assert_eq!(Light::Red.prev(), None);
+        assert_eq!(Light::Off.next(), None);
+
+        assert_eq!(Light::Green.next_cyclic(), Light::Red);
+        assert_eq!(Light::Red.prev_cyclic(), Light::Green);
+        assert_eq!(Light::Yellow.next_cyclic(), Light::Green);
+        assert_eq!(Light::Off.next_cyclic(), Light::Off);
+
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        enum Empty {}
+
+        assert_eq!(Empty::first(), None);
+        assert_eq!(Empty::iter().len(), 0);
+    }
+
+    #[test]
+    fn test_stable_ids() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str(serialize = "lowercase", deserialize = "lowercase", stable_id)]
+        enum Shape {
+            Circle,
+            #[str(code = 17)]
+            Square,
+        }
+
+        const CIRCLE: u32 = Shape::Circle.stable_id();
+
+        assert_eq!(CIRCLE, crate::stable_id("circle"));
+        assert_eq!(crate::stable_id(""), 0x811c_9dc5);
+        assert_eq!(crate::stable_id("a"), 0xe40c_292c);
+        assert_eq!(Shape::Square.stable_id(), 17);
+
+        assert_eq!(Shape::from_stable_id(CIRCLE), Some(Shape::Circle));
+        assert_eq!(Shape::from_stable_id(17), Some(Shape::Square));
+        assert_eq!(Shape::from_stable_id(0), None);
+    }
+
+    #[test]
+    fn test_fixed_width() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str = "UPPERCASE"]
+        enum Side {
+            Buy,
+            #[str(skip_list)]
+            Cancelled,
+            Sell,
+        }
+
+        assert_eq!(Side::MAX_LEN, 9);
+        assert_eq!(Side::MIN_LEN, 3);
+
+        assert_eq!(&Side::Buy.to_padded::<9>(b' '), b"BUY      ");
+        assert_eq!(&Side::Cancelled.to_padded::<10>(0), b"CANCELLED\0");
+        assert_eq!(&Side::Cancelled.to_truncated::<4>(b' '), b"CANC");
+        assert_eq!(&Side::Sell.to_truncated::<6>(b'.'), b"SELL..");
+    }
+
+    #[test]
+    fn test_parse_prefix() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        enum Token {
+            #[str = "<"]
+            Lt,
+            #[str = "<="]
+            Le,
+            #[str = "in"]
+            In,
+            #[str = "int"]
+            Int,
+            #[str(deserialize = "i", skip_parse)]
+            Skipped,
+        }
+
+        assert_eq!(Token::parse_prefix("<=x"), Some((Token::Le, "x")));
+        assert_eq!(Token::parse_prefix("<x"), Some((Token::Lt, "x")));
+        assert_eq!(Token::parse_prefix("inx"), Some((Token::In, "x")));
+        assert_eq!(Token::parse_prefix("int"), Some((Token::Int, "")));
+        assert_eq!(Token::parse_prefix("i"), None);
+        assert_eq!(Token::parse_prefix(""), None);
+
+        assert_eq!(Token::parse_word_prefix("in x"), Some((Token::In, " x")));
+        assert_eq!(Token::parse_word_prefix("intx"), None);
+        assert_eq!(
+            Token::parse_word_prefix("int(x)"),
+            Some((Token::Int, "(x)"))
+        );
+        assert_eq!(Token::parse_word_prefix("<=x"), Some((Token::Le, "x")));
+
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        enum Empty {}
+
+        assert_eq!(Empty::parse_prefix("x"), None);
+    }
+
+    #[test]
+    fn test_manual_impl() {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        enum Op {
+            Lt,
+            Le,
+        }
+
+        impl StringEnum for Op {
+            const VARIANTS: &'static [Self] = &[Self::Lt, Self::Le];
+            const SERIALIZE_NAMES: &'static [&'static str] = &["lt", "le"];
+            const DESERIALIZE_NAMES: &'static [&'static str] = &["<", "<="];
+            const REGEX: &'static str = "(?:<=|<)";
+            const REGEX_ANCHORED: &'static str = "^(?:<=|<)$";
+
+            fn as_str(&self) -> &'static str {
+                match self {
+                    Self::Lt => "lt",
+                    Self::Le => "le",
+                }
+            }
+
+            fn parse_any(s: &str) -> Result<Self, InvalidVariantError> {
+                match s {
+                    "<" | "lt" => Ok(Self::Lt),
+                    "<=" | "le" => Ok(Self::Le),
+                    _ => Err(InvalidVariantError::new(Self::DESERIALIZE_NAMES)),
+                }
+            }
+
+            fn position(&self) -> Option<usize> {
+                Some(*self as usize)
+            }
+        }
+
+        assert_eq!((Op::MAX_LEN, Op::MIN_LEN), (2, 2));
+        assert_eq!(Op::parse_prefix("<=x"), Some((Op::Le, "x")));
+        assert_eq!(Op::parse_prefix("<x"), Some((Op::Lt, "x")));
+        assert_eq!(Op::parse_prefix("x"), None);
+        assert!(Op::find_iter("a < b <= c").eq([(2, Op::Lt), (6, Op::Le)]));
+    }
+
+    #[test]
+    fn test_find_iter() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str = "UPPERCASE"]
+        enum Level {
+            Warn,
+            Error,
+            #[str = "ERR"]
+            Fault,
+        }
+
+        let line = "ERROR: disk full; warn: ERRORS ahead, ERRno";
+        assert!(Level::find_iter(line).eq([
+            (0, Level::Error),
+            (24, Level::Error),
+            (38, Level::Fault),
+        ]));
+        assert!(Level::find_iter(line).ignore_ascii_case().eq([
+            (0, Level::Error),
+            (18, Level::Warn),
+            (24, Level::Error),
+            (38, Level::Fault),
+        ]));
+        assert!(Level::find_iter(line)
+            .ignore_ascii_case()
+            .words()
+            .eq([(0, Level::Error), (18, Level::Warn)]));
+        assert!(Level::find_iter("ünïcödé ERR").eq([(12, Level::Fault)]));
+        assert_eq!(Level::find_iter("").count(), 0);
+    }
+
+    #[test]
+    fn test_regex() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        enum Op {
+            #[str = "<"]
+            Lt,
+            #[str = "<="]
+            Le,
+            #[str = "a.b"]
+            Dot,
+            #[str = "(x|y)*"]
+            Group,
+            #[str(deserialize = "hidden", skip_parse)]
+            Skipped,
+        }
+
+        assert_eq!(Op::REGEX, r"(?:\(x\|y\)\*|a\.b|<=|<)");
+        assert_eq!(Op::REGEX_ANCHORED, r"^(?:\(x\|y\)\*|a\.b|<=|<)$");
+
+        let regex = regex::Regex::new(Op::REGEX).unwrap();
+        let found = regex.find_iter("a<=b axb (x|y)*");
+        assert!(found.map(|m| m.as_str()).eq(["<=", "(x|y)*"]));
+
+        // Like `FromStr`, the `regex` crate does not accept a trailing newline.
+        let regex = regex::Regex::new(Op::REGEX_ANCHORED).unwrap();
+        assert!(regex.is_match("<="));
+        assert!(!regex.is_match("<=\n"));
+
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        enum Empty {}
+
+        let regex = regex::Regex::new(Empty::REGEX_ANCHORED).unwrap();
+        assert!(!regex.is_match(""));
+    }
+
+    #[test]
+    fn test_matching() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str = "snake_case"]
+        enum Target {
+            #[str = "db.query"]
+            DbQuery,
+            #[str = "db.pool"]
+            DbPool,
+            InternalMetrics,
+            InternalTrace,
+            Http,
+        }
+
+        assert!(Target::matching("db.*").eq([Target::DbQuery, Target::DbPool]));
+        assert!(Target::matching("!internal_*").eq([
+            Target::DbQuery,
+            Target::DbPool,
+            Target::Http,
+        ]));
+        assert!(Target::matching("*_[mn]*").eq([Target::InternalMetrics]));
+        assert!(Target::matching("*.[!q]*").eq([Target::DbPool]));
+        assert!(Target::matching("ht?p").eq([Target::Http]));
+        assert!(Target::matching("*").rev().eq(Target::iter().rev()));
+        assert_eq!(Target::matching("db").count(), 0);
+
+        let cases = [
+            ("", "", true),
+            ("*", "", true),
+            ("?", "", false),
+            ("a*b*c", "aXbYbZc", true),
+            ("a*b*c", "aXbYbZ", false),
+            ("[a-c]x", "bx", true),
+            ("[^a-c]x", "bx", false),
+            ("[]]", "]", true),
+            ("[a-]", "-", true),
+            ("[ab", "[ab", true),
+            ("\\*", "*", true),
+            ("\\*", "a", false),
+            ("é?", "éü", true),
+        ];
+        for (pattern, name, expected) in cases {
+            assert_eq!(
+                Glob::new(pattern).is_match(name),
+                expected,
+                "{pattern:?} {name:?}"
+            );
+        }
+    }
+
+    #[cfg(feature = "nom")]
+    #[test]
+    fn test_nom() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str = "lowercase"]
+        enum Keyword {
+            Let,
+            Fn,
+        }
+
+        let mut parser = nom::sequence::preceded(
+            nom::character::complete::space0::<_, nom::error::Error<_>>,
+            crate::nom::parse_word::<Keyword, _>,
+        );
+        assert_eq!(parser("  let x"), Ok((" x", Keyword::Let)));
+        assert!(parser("letter").is_err());
+    }
+
+    #[cfg(feature = "winnow")]
+    #[test]
+    fn test_winnow() {
+        use winnow::Parser;
+
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str = "lowercase"]
+        enum Keyword {
+            Let,
+            Fn,
+        }
+
+        let mut input = "fn main";
+        let keyword =
+            crate::winnow::parse::<Keyword, winnow::error::ContextError>.parse_next(&mut input);
+        assert_eq!(keyword, Ok(Keyword::Fn));
+        assert_eq!(input, " main");
+    }
+
+    #[test]
+    fn test_predicates() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str(predicates)]
+        enum JobState {
+            Queued,
+            InProgress,
+            #[str(group = "terminal", group = "success")]
+            Succeeded,
+            #[str(group = "terminal")]
+            Failed,
+        }
+
+        const IN_PROGRESS: [bool; 2] = [
+            JobState::InProgress.is_in_progress(),
+            JobState::Queued.is_in_progress(),
+        ];
+
+        assert_eq!(IN_PROGRESS, [true, false]);
+        assert!(JobState::Queued.is_queued());
+        assert!(!JobState::Queued.is_failed());
+        assert!(JobState::Failed.is_terminal());
+        assert!(JobState::Succeeded.is_terminal());
+        assert!(!JobState::InProgress.is_terminal());
+        assert!(JobState::Succeeded.is_success());
+        assert!(!JobState::Failed.is_success());
+    }
+
+    #[test]
+    fn test_data_variants() {
+        #[derive(Debug, Default, PartialEq, StringEnum)]
+        #[str = "snake_case"]
+        enum Event {
+            #[default]
+            Started,
+            UserCreated {
+                id: u32,
+            },
+            #[str = "removed"]
+            UserDeleted(u32, alloc::string::String),
+        }
+
+        assert_eq!(Event::Started.as_str(), "started");
+        assert_eq!(Event::UserCreated { id: 7 }.as_str(), "user_created");
+        assert_eq!(
+            alloc::format!("{:>8}", Event::UserDeleted(7, "x".into())),
+            " removed"
+        );
+
+        #[derive(Debug, PartialEq, StringEnum)]
+        #[str(default_fields, error_type = ShapeError)]
+        enum Shape {
+            Point,
+            Circle { radius: u32 },
+            Rect(u32, u32),
+        }
+
+        assert_eq!(Shape::from_str("Point"), Ok(Shape::Point));
+        assert_eq!(Shape::from_str("Circle"), Ok(Shape::Circle { radius: 0 }));
+        assert_eq!(Shape::from_str("Rect"), Ok(Shape::Rect(0, 0)));
+        let err = Shape::from_str("Square").unwrap_err();
+        assert_eq!(
+            alloc::format!("{err}"),
+            "invalid Shape variant, expected one of: Point, Circle or Rect"
+        );
+    }
+
+    #[test]
+    fn test_generic_data_variants() {
+        #[derive(Debug, PartialEq, StringEnum)]
+        enum Message<'a> {
+            Ping,
+            Text(&'a str),
+        }
+
+        assert_eq!(Message::Ping.as_str(), "Ping");
+        assert_eq!(alloc::format!("{}", Message::Text("hi")), "Text");
+
+        #[derive(Debug, PartialEq, StringEnum)]
+        #[str = "lowercase"]
+        #[str(default_fields)]
+        enum Event<T> {
+            Created { id: T },
+        }
+
+        assert_eq!(Event::Created { id: 7 }.as_str(), "created");
+        assert_eq!(Event::from_str("created"), Ok(Event::Created { id: 0_u32 }));
+        assert!(Event::<u32>::from_str("deleted").is_err());
+    }
+
+    #[test]
+    fn test_kind() {
+        #[derive(Debug, PartialEq, StringEnum)]
+        #[str(kind = MessageKind, serialize = "snake_case", deserialize = "snake_case")]
+        #[str(predicates)]
+        enum Message {
+            Ping,
+            #[str = "msg"]
+            Text(alloc::string::String),
+            Unknown {
+                code: u16,
+            },
+        }
+
+        test_enum(
+            &[
+                TestCase::new(MessageKind::Ping, "ping", "ping"),
+                TestCase::new(MessageKind::Text, "msg", "msg"),
+                TestCase::new(MessageKind::Unknown, "unknown", "unknown"),
+            ],
+            "invalid variant, expected one of: ping, msg or unknown",
+        );
+
+        let message = Message::Text("hi".into());
+        assert_eq!(message.kind(), MessageKind::Text);
+        assert_eq!(message.as_str(), "msg");
+        assert_eq!(alloc::format!("{message}"), "msg");
+        assert_eq!(Message::Unknown { code: 1 }.kind(), MessageKind::Unknown);
+        assert!(message.kind().is_text());
+        assert!(Message::Ping.kind().is_ping());
+
+        #[derive(Debug, PartialEq, StringEnum)]
+        #[str(kind = PacketKind)]
+        enum Packet<'a, T> {
+            Header(&'a str),
+            Body { payload: T },
+        }
+
+        assert_eq!(Packet::<()>::Header("v1").kind(), PacketKind::Header);
+        assert_eq!(Packet::Body { payload: 7 }.kind(), PacketKind::Body);
+        assert_eq!(PacketKind::Body.as_str(), "Body");
+    }
+
     #[test]
     fn test_with_ignore_serde_rules() {
         #[derive(Debug, Clone, Copy, PartialEq, StringEnum, Serialize, Deserialize)]
@@ -152,6 +1387,30 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_with_charset() {
+        #[derive(Debug, Clone, Copy, PartialEq, StringEnum)]
+        #[str(
+            serialize = "snake_case",
+            deserialize = "kebab-case",
+            charset = "url_safe"
+        )]
+        #[str(non_empty, max_len = 16)]
+        enum WithCharset {
+            PathSegment,
+            #[str = "query"]
+            QueryString,
+        }
+
+        test_enum(
+            &[
+                TestCase::new(WithCharset::PathSegment, "path_segment", "path-segment"),
+                TestCase::new(WithCharset::QueryString, "query", "query"),
+            ],
+            "invalid variant, expected one of: path-segment or query",
+        );
+    }
+
     struct TestCase<'a, E> {
         variant: E,
         as_str: &'a str,
@@ -176,6 +1435,8 @@ mod test {
         E: Debug + PartialEq + FromStr<Err = InvalidVariantError> + StringEnum,
     {
         assert_eq!(E::VARIANTS.len(), cases.len());
+        assert_eq!(E::SERIALIZE_NAMES.len(), cases.len());
+        assert_eq!(E::DESERIALIZE_NAMES.len(), cases.len());
         let name = core::any::type_name::<E>();
 
         for TestCase {
@@ -196,10 +1457,50 @@ mod test {
                 E::from_str(from_str),
                 "{name}::from_str {variant:?} {from_str:?}"
             );
+
+            assert_eq!(
+                Ok(*variant),
+                E::parse_any(as_str),
+                "{name}::parse_any {variant:?} {as_str:?}"
+            );
+            assert_eq!(
+                Ok(*variant),
+                E::parse_any(from_str),
+                "{name}::parse_any {variant:?} {from_str:?}"
+            );
         }
 
+        let regex = regex::Regex::new(E::REGEX_ANCHORED).unwrap();
+        for TestCase { from_str, .. } in cases {
+            assert!(
+                regex.is_match(from_str),
+                "{name}::REGEX_ANCHORED {from_str:?}"
+            );
+        }
+        assert!(
+            !regex.is_match("invalid"),
+            "{name}::REGEX_ANCHORED \"invalid\""
+        );
+
+        let lens = cases.iter().map(|case| case.as_str.len());
+        assert_eq!(E::MAX_LEN, lens.clone().max().unwrap_or(0));
+        assert_eq!(E::MIN_LEN, lens.min().unwrap_or(0));
+
+        for (i, variant) in E::VARIANTS.iter().enumerate() {
+            assert_eq!(variant.position(), Some(i), "{name}::position {variant:?}");
+        }
+
+        let serialize_names = cases.iter().map(|case| case.as_str);
+        assert!(E::SERIALIZE_NAMES.iter().copied().eq(serialize_names));
+        let deserialize_names = cases.iter().map(|case| case.from_str);
+        assert!(E::DESERIALIZE_NAMES.iter().copied().eq(deserialize_names));
+
         let err = E::from_str("invalid").unwrap_err();
         let err = alloc::format!("{err}");
         assert_eq!(err, error);
+
+        let err = E::parse_any("invalid").unwrap_err();
+        let err = alloc::format!("{err}");
+        assert_eq!(err, error);
     }
 }
@@ -0,0 +1,26 @@
+//! Parsers for use with [`nom`].
+
+use nom::error::ErrorKind;
+use nom::error::ParseError;
+use nom::IResult;
+
+use crate::StringEnum;
+
+/// Parses a variant at the start of the input with
+/// [`StringEnum::parse_prefix`], failing with [`ErrorKind::Tag`].
+pub fn parse<'i, T: StringEnum, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, T, E> {
+    match T::parse_prefix(input) {
+        Some((variant, rest)) => Ok((rest, variant)),
+        None => Err(nom::Err::Error(E::from_error_kind(input, ErrorKind::Tag))),
+    }
+}
+
+/// Like [`parse`], but with [`StringEnum::parse_word_prefix`].
+pub fn parse_word<'i, T: StringEnum, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, T, E> {
+    match T::parse_word_prefix(input) {
+        Some((variant, rest)) => Ok((rest, variant)),
+        None => Err(nom::Err::Error(E::from_error_kind(input, ErrorKind::Tag))),
+    }
+}
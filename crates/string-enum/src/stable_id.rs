@@ -0,0 +1,17 @@
+const FNV_OFFSET_BASIS: u32 = 0x811c_9dc5;
+const FNV_PRIME: u32 = 0x0100_0193;
+
+/// The stable id the derive assigns to a variant with the serialize name
+/// `name`, unless overridden with `#[str(code = N)]`: the 32-bit FNV-1a hash
+/// of its bytes.
+pub const fn stable_id(name: &str) -> u32 {
+    let bytes = name.as_bytes();
+    let mut hash = FNV_OFFSET_BASIS;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u32;
+        hash = hash.wrapping_mul(FNV_PRIME);
+        i += 1;
+    }
+    hash
+}
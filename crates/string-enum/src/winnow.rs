@@ -0,0 +1,28 @@
+//! Parsers for use with [`winnow`].
+
+use winnow::error::ParserError;
+
+use crate::StringEnum;
+
+/// Parses a variant at the start of the input with
+/// [`StringEnum::parse_prefix`], consuming its name.
+pub fn parse<'i, T: StringEnum, E: ParserError<&'i str>>(input: &mut &'i str) -> Result<T, E> {
+    match T::parse_prefix(input) {
+        Some((variant, rest)) => {
+            *input = rest;
+            Ok(variant)
+        }
+        None => Err(E::from_input(input)),
+    }
+}
+
+/// Like [`parse`], but with [`StringEnum::parse_word_prefix`].
+pub fn parse_word<'i, T: StringEnum, E: ParserError<&'i str>>(input: &mut &'i str) -> Result<T, E> {
+    match T::parse_word_prefix(input) {
+        Some((variant, rest)) => {
+            *input = rest;
+            Ok(variant)
+        }
+        None => Err(E::from_input(input)),
+    }
+}